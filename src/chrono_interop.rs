@@ -0,0 +1,153 @@
+//! Conversions between timelang nodes and their [chrono] equivalents.
+
+use super::*;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeDelta, Timelike};
+
+/// Returned when a value cannot be converted between timelang and [chrono] because it is not
+/// representable on the other side, such as `31/2/2024` or a year outside the range of [Year].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct ChronoConversionError;
+
+impl Display for ChronoConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("value is not representable in the target type")
+    }
+}
+
+impl std::error::Error for ChronoConversionError {}
+
+impl TryFrom<Date> for NaiveDate {
+    type Error = ChronoConversionError;
+
+    fn try_from(value: Date) -> std::result::Result<Self, Self::Error> {
+        let Date(month, DayOfMonth(day), Year(year)) = value;
+        NaiveDate::from_ymd_opt(year as i32, u8::from(month) as u32, day as u32)
+            .ok_or(ChronoConversionError)
+    }
+}
+
+impl TryFrom<NaiveDate> for Date {
+    type Error = ChronoConversionError;
+
+    fn try_from(value: NaiveDate) -> std::result::Result<Self, Self::Error> {
+        let year = u16::try_from(value.year()).map_err(|_| ChronoConversionError)?;
+        Ok(Date(
+            MONTHS[value.month0() as usize],
+            DayOfMonth(value.day() as u8),
+            Year(year),
+        ))
+    }
+}
+
+impl TryFrom<Time> for NaiveTime {
+    type Error = ChronoConversionError;
+
+    /// Converts 12-hour times to their 24-hour equivalents (e.g. `12:30 AM` becomes `00:30`).
+    /// Fails for out-of-range times such as `24:00`.
+    fn try_from(value: Time) -> std::result::Result<Self, Self::Error> {
+        let minute_of_day = value.minute_of_day() as u32;
+        NaiveTime::from_hms_opt(minute_of_day / 60, minute_of_day % 60, 0)
+            .ok_or(ChronoConversionError)
+    }
+}
+
+impl From<NaiveTime> for Time {
+    /// Produces a 24-hour [Time], discarding any seconds.
+    fn from(value: NaiveTime) -> Self {
+        Time(
+            Hour::Hour24(value.hour() as u8),
+            Minute(value.minute() as u8),
+        )
+    }
+}
+
+impl TryFrom<DateTime> for NaiveDateTime {
+    type Error = ChronoConversionError;
+
+    fn try_from(value: DateTime) -> std::result::Result<Self, Self::Error> {
+        Ok(NaiveDateTime::new(value.0.try_into()?, value.1.try_into()?))
+    }
+}
+
+impl TryFrom<NaiveDateTime> for DateTime {
+    type Error = ChronoConversionError;
+
+    /// Produces a [DateTime] with a 24-hour [Time], discarding any seconds.
+    fn try_from(value: NaiveDateTime) -> std::result::Result<Self, Self::Error> {
+        Ok(DateTime(value.date().try_into()?, value.time().into()))
+    }
+}
+
+impl Date {
+    /// Compares this [Date] chronologically with a [NaiveDate]. Returns [None] if this [Date]
+    /// does not exist (e.g. `31/2/2024`) and therefore has no [NaiveDate] equivalent.
+    ///
+    /// This is a method rather than a [PartialOrd] impl, since cross-type comparison operators
+    /// would break type inference for ordinary comparisons such as
+    /// `assert_eq!(date, "20/4/2021".parse().unwrap())`.
+    pub fn cmp_naive(&self, other: &NaiveDate) -> Option<Ordering> {
+        Some(NaiveDate::try_from(*self).ok()?.cmp(other))
+    }
+}
+
+impl DateTime {
+    /// Compares this [DateTime] chronologically with a [NaiveDateTime], treating it as falling
+    /// on the exact minute (so it comes before any [NaiveDateTime] with non-zero seconds in
+    /// that same minute), and `24:00` as midnight at the start of the following day. Returns
+    /// [None] if the [Date] does not exist. See [Date::cmp_naive].
+    pub fn cmp_naive(&self, other: &NaiveDateTime) -> Option<Ordering> {
+        let (time, next_day) = self.1.normalize();
+        let date = NaiveDate::try_from(self.0).ok()?;
+        let date = if next_day { date.succ_opt()? } else { date };
+        let time = NaiveTime::try_from(time).ok()?;
+        Some(NaiveDateTime::new(date, time).cmp(other))
+    }
+}
+
+impl Duration {
+    /// Converts this [Duration] to a [chrono::Duration].
+    ///
+    /// Since months and years do not have a fixed length, they are approximated as 30 and 365
+    /// days, respectively. Durations too large to be represented saturate to
+    /// [chrono::Duration::MAX].
+    pub fn to_chrono(&self) -> chrono::Duration {
+        i64::try_from(self.approximate_minutes())
+            .ok()
+            .and_then(TimeDelta::try_minutes)
+            .unwrap_or(TimeDelta::MAX)
+    }
+}
+
+#[cfg(feature = "std-clock")]
+impl DateTime {
+    /// The current local time according to the system clock (and the system's time zone),
+    /// truncated to the minute.
+    fn local_now() -> DateTime {
+        chrono::Local::now()
+            .naive_local()
+            .try_into()
+            .expect("the current year is representable")
+    }
+}
+
+#[cfg(feature = "std-clock")]
+impl RelativeTime {
+    /// Like [RelativeTime::resolve], but resolves against the current time read from the
+    /// system clock, rather than an explicit `now`.
+    ///
+    /// The current time is taken in the system's local time zone, so the result is a local
+    /// wall-clock time. Since timelang nodes carry no seconds, "now" is truncated to the
+    /// minute.
+    pub fn resolve_now(&self) -> DateTime {
+        self.resolve(DateTime::local_now())
+    }
+}
+
+#[cfg(feature = "std-clock")]
+impl PointInTime {
+    /// Like [PointInTime::to_absolute], but resolves any relative components against the
+    /// current local time read from the system clock; see [RelativeTime::resolve_now].
+    pub fn to_absolute_now(&self) -> AbsoluteTime {
+        self.to_absolute(DateTime::local_now())
+    }
+}
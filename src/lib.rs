@@ -17,8 +17,10 @@
 //! and [TimeRange].
 //!
 //! All nodes in timelang impl [FromStr] as well as [syn::parse::Parse] which is used for the
-//! internal parsing logic. The standard [Display] impl is used on all node types as the
-//! preferred means of outputting them to a string.
+//! internal parsing logic. Parsing via [FromStr] reports failures as a [ParseError], which
+//! records the character offset within the input at which parsing failed. The standard
+//! [Display] impl is used on all node types as the preferred means of outputting them to a
+//! string.
 //!
 //! Note that for the moment, only years, months, weeks, days, hours, and minutes are supported
 //! in timelang, but seconds and more might be added later. Generally better than minute
@@ -44,6 +46,28 @@
 //! - `from 1/1/2023 at 14:07 to 15/1/2023` ([TimeRange])
 //! - `from 19/3/2024 at 10:07 AM to 3 months 2 days after 3/9/2027 at 5:27 PM` ([TimeRange])
 //! - `2 days and 14 hours after the day after tomorrow` ([RelativeTime])
+//! - `for the next 2 weeks and 3 days` ([TimeRange])
+//! - `now +09:00` ([RelativeTime])
+//! - `this time next week` ([RelativeTime])
+//! - `from 1/1/2024` ([OpenTimeRange])
+//! - `in 2 hours and 15 minutes` ([RelativeTime])
+//!
+//!
+//! ## Features
+//!
+//! * `serde`: derives [serde](https://crates.io/crates/serde) `Serialize` and `Deserialize` for
+//!   all AST nodes, and provides the [serde_string] helper module for (de)serializing nodes as
+//!   their human-readable [Display] form. Also enables
+//!   [TimeExpression::resolution_report].
+//! * `chrono`: conversions between timelang's [Date], [Time], and [DateTime] and their
+//!   [chrono](https://crates.io/crates/chrono) `Naive*` equivalents, chronological
+//!   comparisons of [Date] and [DateTime] against `NaiveDate` and `NaiveDateTime` (see
+//!   [Date::cmp_naive] and [DateTime::cmp_naive]), as well as [Duration::to_chrono].
+//! * `std-clock`: implies `chrono`, and adds [RelativeTime::resolve_now] and
+//!   [PointInTime::to_absolute_now], which resolve against the current local time read from
+//!   the system clock instead of an explicit `now`.
+//! * `testing`: exports [assert_roundtrip], for checking in downstream test suites that
+//!   values survive a [Display] / [FromStr] round trip.
 //!
 //!
 //! ## Context Free Grammar
@@ -51,25 +75,41 @@
 //!
 //! ```cfg
 //! S → TimeExpression
-//! TimeExpression → PointInTime | TimeRange | Duration
+//! TimeExpression → PointInTime | TimeRange | OpenTimeRange | Duration | AllDay | Recurrence
+//! Quarter → 'Q1' | 'Q2' | 'Q3' | 'Q4'
+//! Recurrence → 'every' (Duration | TimeUnit | 'fortnight') ('starting' PointInTime)?
+//! AllDay → 'all' 'day' Date | Date 'all' 'day'
 //! PointInTime → AbsoluteTime | RelativeTime
-//! TimeRange → 'from' PointInTime 'to' PointInTime
-//! Duration → Number TimeUnit ((','? 'and')? Number TimeUnit)*
+//! TimeRange → 'from' PointInTime ('to' | 'through') PointInTime | 'between' PointInTime 'and' PointInTime | Quarter Year | 'week' Number 'of' Year | 'from' Clock ('to' | 'through') Clock ('on' Date)? | Date 'from' Clock ('to' | 'through') Clock | 'for' 'the'? ('next' | 'past' | 'last') Duration | 'during'? ('office' | 'working') 'hours' ('on' Date)? | 'rest' 'of' 'the'? ('day' | 'week' | 'month' | 'year')
+//! OpenTimeRange → ('from' | 'after') PointInTime | ('to' | 'until' | 'before') PointInTime
+//! Duration → (Quantity (TimeUnit | 'fortnight') | Fraction) (','? 'and'? (Quantity (TimeUnit | 'fortnight') | Fraction))* ','?
+//! Quantity → Number | 'a' | 'an' | NumberWord
+//! NumberWord → 'one' | 'two' | … | 'nineteen'
+//! Fraction → 'a'? ('half' ('a' | 'an')? | 'quarter' 'of'? ('a' | 'an')?) 'hour'
+//! SignedDuration → ('-' | 'minus')? Duration
 //! AbsoluteTime → Date | DateTime
-//! RelativeTime → Duration TimeDirection | NamedRelativeTime | 'next' RelativeTimeUnit | 'last' RelativeTimeUnit
-//! NamedRelativeTime → 'now' | 'today' | 'tomorrow' | 'yesterday' | 'day after tomorrow' | 'the day after tomorrow' | 'day before yesterday' | 'the day before yesterday'
-//! Date → DayOfMonth '/' Month '/' Year
-//! DateTime → Date ('at')? Time
-//! Time → Hour ':' Minute AmPm?
+//! RelativeTime → Duration TimeDirection | 'in' Duration ("'s"? 'time')? | ('+' | '-') Duration | NamedRelativeTime | 'next' RelativeTimeUnit | 'last' RelativeTimeUnit | 'this' RelativeTimeUnit | Weekday | 'the'? Weekday 'after' 'next' | 'this' 'time' (NamedRelativeTime | 'next' RelativeTimeUnit | 'last' RelativeTimeUnit) | NamedRelativeTime 'at' QualifiedTime | 'at'? ('noon' | 'midnight') 'sharp'? NamedRelativeTime | 'at' QualifiedTime NamedRelativeTime | 'exactly' Time 'sharp'? NamedRelativeTime
+//! Sequence → RelativeTime ('and' 'then' (RelativeTime | Duration 'later'))*
+//! NamedRelativeTime → 'now' UtcOffset? | 'today' | 'tomorrow' | 'yesterday' | 'the'? 'day' '-'? 'after' '-'? 'tomorrow' | 'the'? 'day' '-'? 'before' '-'? 'yesterday' | 'midday' | 'mid' '-'? 'day' | 'end' '-'? 'of' '-'? 'the'? ('day' | 'week' | 'month' | 'year')
+//! Date → DayOfMonth '/' Month '/' Year | Year '/' Month '/' DayOfMonth | Year '-' Month '-' DayOfMonth | DayOfMonth '/'? MonthName '/'? Year
+//! DateTime → Date ('at')? Time | Year '-' Month '-' DayOfMonth 'T' Time
+//! OffsetDateTime → DateTime UtcOffset
+//! OffsetTime → Time UtcOffset?
+//! Time → Hour ':' Minute AmPm? | Hour AmPm | 'noon' | 'midnight'
+//! QualifiedTime → 'exactly'? Time 'sharp'?
+//! Clock → Hour (':' Minute)? AmPm? | 'noon' | 'midnight'
 //! Hour → Number
 //! Minute → Number
-//! Month → Number
-//! DayOfMonth → Number
+//! Month → Number | MonthName
+//! MonthName → 'january' | 'jan' | 'february' | 'feb' | ... | 'december' | 'dec'
+//! DayOfMonth → Number ('st' | 'nd' | 'rd' | 'th')?
 //! Year → Number
 //! AmPm → 'AM' | 'PM'
+//! UtcOffset → ('+' | '-') Number ':' Number | 'UTC' | 'GMT' | 'Z' | [Common time zone abbreviation]
 //! TimeUnit → 'minutes' | 'hours' | 'days' | 'weeks' | 'months' | 'years'
-//! TimeDirection → 'after' AbsoluteTime | 'before' AbsoluteTime | 'after' NamedRelativeTime | 'before' NamedRelativeTime | 'before' 'next' RelativeTimeUnit | 'before' 'last' RelativeTimeUnit | 'after' 'next' RelativeTimeUnit | 'after' 'last' RelativeTimeUnit | 'ago' | 'from now'
-//! RelativeTimeUnit → 'week' | 'month' | 'year' | 'monday' | 'tuesday' | 'wednesday' | 'thursday' | 'friday' | 'saturday' | 'sunday'
+//! TimeDirection → 'after' AbsoluteTime | 'before' AbsoluteTime | 'after' NamedRelativeTime | 'before' NamedRelativeTime | 'before' 'next' RelativeTimeUnit | 'before' 'last' RelativeTimeUnit | 'after' 'next' RelativeTimeUnit | 'after' 'last' RelativeTimeUnit | 'ago' ('today' | 'tomorrow')? | 'from now' | 'today' | 'tomorrow'
+//! RelativeTimeUnit → 'week' | 'month' | 'year' | Weekday
+//! Weekday → 'monday' | 'tuesday' | 'wednesday' | 'thursday' | 'friday' | 'saturday' | 'sunday'
 //! Number → [Any positive integer value]
 //! ```
 //!
@@ -90,18 +130,24 @@
 #![deny(missing_docs)]
 
 use std::{
+    cmp::Ordering,
     fmt::Display,
     ops::{Add, Div, Mul, Sub},
     str::FromStr,
 };
 use syn::{
     parse::{Parse, ParseStream, Result},
-    Error, Ident, LitInt, Token,
+    Error, Ident, Lifetime, LitInt, Token,
 };
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "chrono")]
+mod chrono_interop;
+#[cfg(feature = "chrono")]
+pub use chrono_interop::*;
+
 /// The top-level entry-point for the timelang AST.
 ///
 /// Typically you will want to use a more specific type like [Duration], [PointInTime], or
@@ -113,6 +159,13 @@ mod tests;
 /// expressions in timelang does require some temporary allocations that go away when parsing
 /// is complete.
 ///
+/// ## Disambiguation
+///
+/// A [TimeExpression] starting with `from` is a [TimeRange] if the first [PointInTime] is
+/// followed by `to`, and otherwise an [OpenTimeRange]. Expressions starting with `to`,
+/// `until`, `after` or `before` are always [OpenTimeRange]s, since a [RelativeTime] using
+/// `after` or `before` (like "3 days after 1/1/2020") always starts with a [Duration].
+///
 /// ## Examples
 ///
 /// Specific Date:
@@ -390,28 +443,79 @@ mod tests;
 /// );
 /// ```
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeExpression {
     /// Represents a [PointInTime] expression.
     Specific(PointInTime), // (LitInt, Ident) or (LitInt, Token![/])
     /// Represents a [TimeRange] expression.
     Range(TimeRange), // Ident, LitInt
+    /// Represents an [OpenTimeRange] expression, i.e. a range with only one bound.
+    OpenRange(OpenTimeRange), // Ident, LitInt
     /// Represents a [Duration] expression.
     Duration(Duration), // LitInt, Ident
+    /// Represents an entire day, like "all day 5/6/2024" or "5/6/2024 all day". See
+    /// [Date::all_day] for the equivalent [TimeRange].
+    AllDay(Date), // Ident, LitInt or LitInt, Token![/]
+    /// Represents a [Recurrence] expression, like "every 2 weeks".
+    Recurrence(Recurrence), // Ident
 }
 
 impl Parse for TimeExpression {
     fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![for])
+            || TimeRange::peek_office_hours(input)
+            || TimeRange::peek_rest(input)
+        {
+            return Ok(TimeExpression::Range(input.parse()?));
+        }
+        if input.peek(Token![in]) || input.peek(Token![+]) || input.peek(Token![-]) {
+            return Ok(TimeExpression::Specific(input.parse()?));
+        }
         if !input.peek(Ident) && !input.peek(LitInt) {
             return Err(Error::new(input.span(), "expected [number] or [keyword]"));
         }
-        if input.peek(Ident) {
+        if input.peek(Ident) && !Duration::peek_quantity(input) {
             let ident = input.fork().parse::<Ident>()?;
-            if ident.to_string().to_lowercase().as_str() == "from" {
-                return Ok(TimeExpression::Range(input.parse()?));
+            match ident.to_string().to_lowercase().as_str() {
+                "from" => {
+                    // `from X to Y` is a full range, a bare `from X` is open-ended
+                    let fork = input.fork();
+                    fork.parse::<Ident>()?;
+                    if TimeRange::peek_clock_range(&fork) {
+                        return Ok(TimeExpression::Range(input.parse()?));
+                    }
+                    fork.parse::<PointInTime>()?;
+                    if OpenTimeRange::peek_to(&fork) {
+                        return Ok(TimeExpression::Range(input.parse()?));
+                    }
+                    return Ok(TimeExpression::OpenRange(input.parse()?));
+                }
+                "to" | "until" | "after" | "before" => {
+                    return Ok(TimeExpression::OpenRange(input.parse()?))
+                }
+                "between" => return Ok(TimeExpression::Range(input.parse()?)),
+                _ if TimeRange::peek_period(input) => {
+                    return Ok(TimeExpression::Range(input.parse()?))
+                }
+                "all" => {
+                    Date::parse_all_day(input)?;
+                    return Ok(TimeExpression::AllDay(input.parse()?));
+                }
+                "every" => return Ok(TimeExpression::Recurrence(input.parse()?)),
+                _ => (),
             }
             return Ok(TimeExpression::Specific(input.parse()?));
         }
-        if input.peek(LitInt) && input.peek2(Token![/]) {
+        if TimeRange::peek_date_clock_range(input) {
+            return Ok(TimeExpression::Range(input.parse()?));
+        }
+        if Date::peek(input) {
+            let fork = input.fork();
+            if fork.parse::<Date>().is_ok() && Date::parse_all_day(&fork).is_ok() {
+                let date = input.parse::<Date>()?;
+                Date::parse_all_day(input)?;
+                return Ok(TimeExpression::AllDay(date));
+            }
             // case 2 for PointInTime
             return Ok(TimeExpression::Specific(input.parse()?));
         }
@@ -429,243 +533,2344 @@ impl Display for TimeExpression {
         match self {
             TimeExpression::Specific(point) => write!(f, "{point}"),
             TimeExpression::Range(tr) => write!(f, "{tr}"),
+            TimeExpression::OpenRange(tr) => write!(f, "{tr}"),
             TimeExpression::Duration(dur) => write!(f, "{dur}"),
+            TimeExpression::AllDay(date) => write!(f, "all day {date}"),
+            TimeExpression::Recurrence(recurrence) => write!(f, "{recurrence}"),
         }
     }
 }
 
-/// Represents a range of two valid [PointInTime]s that together define the start and end of
-/// some defined period of time.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
-pub struct TimeRange(pub PointInTime, pub PointInTime);
+impl TimeExpression {
+    /// Parses a [TimeExpression] from loosely-formatted input, such as text taken from a chat
+    /// message, by trimming surrounding whitespace and trailing sentence punctuation (`.`, `!`,
+    /// `?`, `,`, `;`) and collapsing runs of internal whitespace before delegating to the
+    /// normal parser. Input that is otherwise malformed still fails to parse, with the
+    /// [ParseError] offset referring to the original (untrimmed) input.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(
+    ///     TimeExpression::parse_lenient("  3 days   ago. ").unwrap(),
+    ///     "3 days ago".parse::<TimeExpression>().unwrap()
+    /// );
+    /// assert_eq!(TimeExpression::parse_lenient("  3 days agoo.").unwrap_err().offset, 9);
+    /// ```
+    pub fn parse_lenient(s: &str) -> std::result::Result<TimeExpression, ParseError> {
+        // only trailing characters are trimmed, so that offsets still line up with `s` (the
+        // tokenizer already skips any other whitespace)
+        let s = s.trim_end_matches(|c: char| c.is_whitespace() || ".!?,;".contains(c));
+        parse_str_with_offset(s, TimeExpression::parse)
+    }
 
-impl Parse for TimeRange {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let ident = input.parse::<Ident>()?;
-        if ident.to_string().to_lowercase() != "from" {
-            return Err(Error::new(ident.span(), "expected `from`"));
+    /// Parses `s` as each kind of [TimeExpression] in turn, returning every interpretation that
+    /// consumes the entire input, without duplicates. The interpretation chosen by the regular
+    /// parser (see [TimeExpression#disambiguation]), if any, always comes first, followed by
+    /// the others in variant order. An empty [Vec] is returned if `s` does not parse at all.
+    ///
+    /// Since timelang is designed to be unambiguous (see the crate-level docs), every input
+    /// currently known to parse yields exactly one interpretation: for example, "3 days" is
+    /// only a [Duration], and "3 days ago" only a [PointInTime]. Multiple interpretations can
+    /// therefore only arise from an ambiguity in the grammar, which is worth reporting as a
+    /// bug. This makes [TimeExpression::parse_all] useful for checking inputs for ambiguity.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let all = TimeExpression::parse_all("3 days ago");
+    /// assert_eq!(all, vec!["3 days ago".parse::<TimeExpression>().unwrap()]);
+    /// assert!(TimeExpression::parse_all("3 dayz").is_empty());
+    /// ```
+    pub fn parse_all(s: &str) -> Vec<TimeExpression> {
+        use syn::parse::Parser;
+        let s = normalize_input(s).0;
+        let all_day = |input: ParseStream| {
+            if Date::peek(input) {
+                let date = input.parse::<Date>()?;
+                Date::parse_all_day(input)?;
+                Ok(date)
+            } else {
+                Date::parse_all_day(input)?;
+                input.parse::<Date>()
+            }
+        };
+        let mut all = Vec::new();
+        let interpretations = [
+            TimeExpression::parse.parse_str(&s),
+            PointInTime::parse
+                .parse_str(&s)
+                .map(TimeExpression::Specific),
+            TimeRange::parse.parse_str(&s).map(TimeExpression::Range),
+            OpenTimeRange::parse
+                .parse_str(&s)
+                .map(TimeExpression::OpenRange),
+            Duration::parse.parse_str(&s).map(TimeExpression::Duration),
+            all_day.parse_str(&s).map(TimeExpression::AllDay),
+            Recurrence::parse
+                .parse_str(&s)
+                .map(TimeExpression::Recurrence),
+        ];
+        for expr in interpretations.into_iter().flatten() {
+            if !all.contains(&expr) {
+                all.push(expr);
+            }
         }
-        let t1 = input.parse::<PointInTime>()?;
-        let ident = input.parse::<Ident>()?;
-        if ident.to_string().to_lowercase() != "to" {
-            return Err(Error::new(ident.span(), "expected `to`"));
+        all
+    }
+
+    /// Returns an upper bound on the length of the [Display] output of this [TimeExpression],
+    /// suitable for preallocating buffers via [String::with_capacity]. The bound is computed
+    /// cheaply by summing the maximum lengths of the individual components, so it may
+    /// overestimate considerably.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let expr = "3 days after 18/3/2024".parse::<TimeExpression>().unwrap();
+    /// assert!(expr.display_len_hint() >= expr.to_string().len());
+    /// ```
+    pub fn display_len_hint(&self) -> usize {
+        match self {
+            TimeExpression::Specific(point) => point.len_hint(),
+            TimeExpression::Range(TimeRange(start, end)) => {
+                "from ".len() + start.len_hint() + " to ".len() + end.len_hint()
+            }
+            TimeExpression::OpenRange(OpenTimeRange::From(point) | OpenTimeRange::To(point)) => {
+                "from ".len() + point.len_hint()
+            }
+            TimeExpression::Duration(duration) => duration.len_hint(),
+            TimeExpression::AllDay(_) => "all day ".len() + DATE_LEN_HINT,
+            TimeExpression::Recurrence(recurrence) => {
+                "every ".len()
+                    + recurrence.interval.len_hint()
+                    + recurrence
+                        .start
+                        .map_or(0, |start| " starting ".len() + start.len_hint())
+            }
         }
-        let t2 = input.parse::<PointInTime>()?;
-        Ok(TimeRange(t1, t2))
     }
-}
 
-impl Display for TimeRange {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "from {} to {}", self.0, self.1)
+    /// Classifies this [TimeExpression] as being in the past, present, or future relative to
+    /// `now`.
+    ///
+    /// [PointInTime]s are resolved (see [PointInTime::resolve]) and compared against `now`
+    /// directly, and are only considered [TemporalPosition::Present] if they resolve to exactly
+    /// `now`. [TimeRange]s are [TemporalPosition::Past] if they end before `now`,
+    /// [TemporalPosition::Future] if they start after `now`, and [TemporalPosition::Present] if
+    /// they contain `now`. [Duration]s and [Recurrence]s are not anchored to any point in time,
+    /// and thus always return [None].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// assert_eq!(
+    ///     "3 days ago".parse::<TimeExpression>().unwrap().temporal_position(now),
+    ///     Some(TemporalPosition::Past)
+    /// );
+    /// ```
+    pub fn temporal_position(&self, now: DateTime) -> Option<TemporalPosition> {
+        let now_minutes = now.minutes();
+        let (start, end) = match self {
+            TimeExpression::Specific(point) => {
+                let minutes = point.resolve(now).minutes();
+                (minutes, minutes)
+            }
+            TimeExpression::Range(range) => range.resolved_bounds(now),
+            TimeExpression::OpenRange(OpenTimeRange::From(start)) => {
+                (start.resolve(now).minutes(), i128::MAX)
+            }
+            TimeExpression::OpenRange(OpenTimeRange::To(end)) => {
+                (i128::MIN, end.resolve(now).minutes())
+            }
+            TimeExpression::AllDay(date) => date.all_day().resolved_bounds(now),
+            TimeExpression::Duration(_) | TimeExpression::Recurrence(_) => return None,
+        };
+        Some(if end < now_minutes {
+            TemporalPosition::Past
+        } else if start > now_minutes {
+            TemporalPosition::Future
+        } else {
+            TemporalPosition::Present
+        })
+    }
+
+    /// Returns `true` if this [TimeExpression] lies entirely before `now`. See
+    /// [TimeExpression::temporal_position].
+    pub fn is_in_past(&self, now: DateTime) -> bool {
+        self.temporal_position(now) == Some(TemporalPosition::Past)
+    }
+
+    /// Returns `true` if this [TimeExpression] lies entirely after `now`. See
+    /// [TimeExpression::temporal_position].
+    pub fn is_in_future(&self, now: DateTime) -> bool {
+        self.temporal_position(now) == Some(TemporalPosition::Future)
+    }
+
+    /// Returns `true` if nothing in this [TimeExpression] depends on the reference "now", i.e.
+    /// if it resolves to the same result regardless of the `now` it is resolved against.
+    ///
+    /// [Duration]s and all-day expressions are always absolute, whereas a [Recurrence] is only
+    /// absolute if it is anchored to an absolute `start`.
+    pub fn is_absolute(&self) -> bool {
+        match self {
+            TimeExpression::Specific(point)
+            | TimeExpression::OpenRange(OpenTimeRange::From(point) | OpenTimeRange::To(point)) => {
+                !point.requires_now()
+            }
+            TimeExpression::Range(TimeRange(start, end)) => {
+                !start.requires_now() && !end.requires_now()
+            }
+            TimeExpression::Duration(_) | TimeExpression::AllDay(_) => true,
+            TimeExpression::Recurrence(recurrence) => {
+                recurrence.start.is_some_and(|start| !start.requires_now())
+            }
+        }
+    }
+
+    /// "Freezes" this [TimeExpression] at `now`, returning an equivalent expression that is
+    /// [absolute](TimeExpression::is_absolute), which is useful for persisting expressions
+    /// that were entered relative to a known instant.
+    ///
+    /// Every relative [PointInTime] is replaced by the [AbsoluteTime] it resolves to (see
+    /// [PointInTime::to_absolute]), while parts that are already absolute are left untouched.
+    /// A [Recurrence] without a `start` is anchored to `now`, which is where its occurrences
+    /// would otherwise be counted from.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let expr = "from 3 days ago to 1/4/2024".parse::<TimeExpression>().unwrap();
+    /// let baked = expr.bake(now);
+    /// assert!(baked.is_absolute());
+    /// assert_eq!(baked.to_string(), "from 12/3/2024 at 10:30 to 1/4/2024");
+    /// ```
+    pub fn bake(&self, now: DateTime) -> TimeExpression {
+        let bake = |point: &PointInTime| PointInTime::Absolute(point.to_absolute(now));
+        match self {
+            TimeExpression::Specific(point) => TimeExpression::Specific(bake(point)),
+            TimeExpression::Range(TimeRange(start, end)) => {
+                TimeExpression::Range(TimeRange(bake(start), bake(end)))
+            }
+            TimeExpression::OpenRange(OpenTimeRange::From(start)) => {
+                TimeExpression::OpenRange(OpenTimeRange::From(bake(start)))
+            }
+            TimeExpression::OpenRange(OpenTimeRange::To(end)) => {
+                TimeExpression::OpenRange(OpenTimeRange::To(bake(end)))
+            }
+            TimeExpression::Duration(_) | TimeExpression::AllDay(_) => *self,
+            TimeExpression::Recurrence(Recurrence { interval, start }) => {
+                TimeExpression::Recurrence(Recurrence {
+                    interval: *interval,
+                    start: Some(match start {
+                        Some(start) => bake(start),
+                        None => PointInTime::Absolute(AbsoluteTime::DateTime(now)),
+                    }),
+                })
+            }
+        }
+    }
+
+    /// Resolves this [TimeExpression] against `now` and describes the result as a JSON object,
+    /// for debugging and inspection purposes.
+    ///
+    /// The object always contains a `"kind"` (one of `"point"`, `"range"`, `"open_range"`,
+    /// `"duration"`, `"all_day"` or `"recurrence"`) and a `"required_now"` flag indicating
+    /// whether the result depends on `now`. Resolved instants are rendered using the [Display]
+    /// impl of [DateTime], under `"instant"` for points, `"start"` and/or `"end"` for ranges,
+    /// and `"next"` for recurrences. Durations and recurrence intervals are rendered under
+    /// `"duration"` and `"interval"` respectively.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let expr = "for the next 2 days".parse::<TimeExpression>().unwrap();
+    /// assert_eq!(
+    ///     expr.resolution_report(now),
+    ///     serde_json::json!({
+    ///         "kind": "range",
+    ///         "start": "15/3/2024 at 10:30",
+    ///         "end": "17/3/2024 at 10:30",
+    ///         "required_now": true,
+    ///     })
+    /// );
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn resolution_report(&self, now: DateTime) -> serde_json::Value {
+        use serde_json::json;
+        let instant = |point: &PointInTime| point.resolve(now).to_string();
+        match self {
+            TimeExpression::Specific(point) => json!({
+                "kind": "point",
+                "instant": instant(point),
+                "required_now": point.requires_now(),
+            }),
+            TimeExpression::Range(TimeRange(start, end)) => json!({
+                "kind": "range",
+                "start": instant(start),
+                "end": instant(end),
+                "required_now": start.requires_now() || end.requires_now(),
+            }),
+            TimeExpression::OpenRange(OpenTimeRange::From(start)) => json!({
+                "kind": "open_range",
+                "start": instant(start),
+                "required_now": start.requires_now(),
+            }),
+            TimeExpression::OpenRange(OpenTimeRange::To(end)) => json!({
+                "kind": "open_range",
+                "end": instant(end),
+                "required_now": end.requires_now(),
+            }),
+            TimeExpression::Duration(duration) => json!({
+                "kind": "duration",
+                "duration": duration.to_string(),
+                "required_now": false,
+            }),
+            TimeExpression::AllDay(date) => {
+                let TimeRange(start, end) = date.all_day();
+                json!({
+                    "kind": "all_day",
+                    "start": instant(&start),
+                    "end": instant(&end),
+                    "required_now": false,
+                })
+            }
+            TimeExpression::Recurrence(recurrence) => json!({
+                "kind": "recurrence",
+                "interval": recurrence.interval.to_string(),
+                "next": recurrence.next_after(now).to_string(),
+                "required_now": recurrence.start.is_none_or(|start| start.requires_now()),
+            }),
+        }
     }
 }
 
-/// Represents a specific duration of time that is not anchored at any particular point in time.
+/// The position of a [TimeExpression] in time relative to some reference point, as returned by
+/// [TimeExpression::temporal_position].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemporalPosition {
+    /// Entirely before the reference point.
+    Past,
+    /// Coincides with or contains the reference point.
+    Present,
+    /// Entirely after the reference point.
+    Future,
+}
+
+/// Represents a range of two valid [PointInTime]s that together define the start and end of
+/// some defined period of time.
 ///
-/// Note that individual components, if not specified, will be recorded as `0`. Such components
-/// will not appear when the [Duration] is rendered, printed, or displayed.
+/// In addition to the canonical `from [PointInTime] to [PointInTime]` form, the phrasings `for
+/// the next [Duration]` and `for the past [Duration]` are accepted as shorthand for `from now to
+/// [Duration] from now` and `from [Duration] ago to now`, respectively. These are normalized to
+/// the canonical form when displayed. `through` may also be used in place of `to`, and
+/// `between [PointInTime] and [PointInTime]` is accepted as another spelling of the canonical
+/// form. Since a [Duration] only consumes an `and` that is followed by another quantity,
+/// endpoints like "2 days and 3 hours ago" don't end the range early.
+///
+/// Ranges of clock times sharing a date can be written compactly as `from [time] to [time]
+/// (on [Date])?`, where each time may omit its minutes and/or `AM` / `PM`, as in "from 9 to 11
+/// AM on 5/6/2024". This produces a range of [DateTime]s on the given [Date] (or times
+/// [NamedRelativeTime::Today] if no date is given). If only one endpoint specifies `AM` / `PM`,
+/// the other endpoint uses the same one, unless that would make the start come after the end,
+/// in which case it uses the opposite one (so "from 11 to 1 PM" starts at 11 AM). If neither
+/// endpoint specifies `AM` / `PM`, both are 24-hour times. Either endpoint may also be `noon`
+/// or `midnight`.
+///
+/// The date may instead come first, as in "5/6/2024 from noon to midnight". In this form, an
+/// end time earlier than the start time (or `24:00`) rolls over to the following day, so
+/// "5/6/2024 from 22:00 to 02:00" ends at 2:00 on 6/6/2024.
+///
+/// Similarly, `(during)? (office | working) hours (on [Date])?` produces a range spanning the
+/// [OfficeHours] on the given date (or today), e.g. "during office hours on 5/6/2024". This is
+/// displayed as the equivalent explicit range.
+///
+/// Calendar periods can be given as a quarter, as in "Q1 2024", or as an ISO 8601 week, as in
+/// "week 3 of 2024" (weeks start on Monday, and week 1 is the week containing the 4th of
+/// January). Both produce a range from 0:00 on the first day of the period to 23:59 on its
+/// last day, and are displayed as such.
+///
+/// `rest of (the)? (day | week | month | year)` produces a range from now until the end of
+/// the current period, i.e. [NamedRelativeTime::EndOfDay], [NamedRelativeTime::EndOfWeek],
+/// etc. (weeks start on Monday). Such ranges are displayed in the `rest of the [period]` form,
+/// including when written explicitly as e.g. "from now to end of week".
+///
+/// ```
+/// use timelang::*;
+/// assert_eq!(
+///     "for the next 2 weeks and 3 days".parse::<TimeRange>().unwrap().to_string(),
+///     "from now to 2 weeks, 3 days from now"
+/// );
+/// ```
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
-pub struct Duration {
-    /// The number of minutes.
-    pub minutes: Number,
-    /// The number of hours.
-    pub hours: Number,
-    /// The number of days.
-    pub days: Number,
-    /// The number of weeks.
-    pub weeks: Number,
-    /// The number of months.
-    pub months: Number,
-    /// The number of years.
-    pub years: Number,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeRange(pub PointInTime, pub PointInTime);
+
+/// The bounds used for `office hours` / `working hours` ranges, as passed to
+/// [TimeRange::parse_with_office_hours]. Defaults to 9:00 to 17:00.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OfficeHours {
+    /// The start of the working day.
+    pub start: Time,
+    /// The end of the working day.
+    pub end: Time,
 }
 
-impl Parse for Duration {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let mut minutes: Option<Number> = None;
-        let mut hours: Option<Number> = None;
-        let mut days: Option<Number> = None;
-        let mut weeks: Option<Number> = None;
-        let mut months: Option<Number> = None;
-        let mut years: Option<Number> = None;
-        while input.peek(LitInt) {
-            let num = input.parse::<Number>()?;
-            let unit = input.parse::<TimeUnit>()?;
-            match unit {
-                TimeUnit::Minutes => minutes = Some(minutes.unwrap_or(Number(0)) + num),
-                TimeUnit::Hours => hours = Some(hours.unwrap_or(Number(0)) + num),
-                TimeUnit::Days => days = Some(days.unwrap_or(Number(0)) + num),
-                TimeUnit::Weeks => weeks = Some(weeks.unwrap_or(Number(0)) + num),
-                TimeUnit::Months => months = Some(months.unwrap_or(Number(0)) + num),
-                TimeUnit::Years => years = Some(years.unwrap_or(Number(0)) + num),
-            }
-            if input.peek(Token![,]) {
-                input.parse::<Token![,]>()?;
-            }
-            if input.peek(Ident) {
-                let ident = input.fork().parse::<Ident>()?; // don't consume if it isn't `and`
-                if ident.to_string().to_lowercase() == "and" {
-                    input.parse::<Ident>()?; // consume the `and`
-                }
-            }
+impl Default for OfficeHours {
+    fn default() -> Self {
+        OfficeHours {
+            start: Time(Hour::Hour24(9), Minute(0)),
+            end: Time(Hour::Hour24(17), Minute(0)),
         }
-        if minutes.is_none()
-            && hours.is_none()
-            && days.is_none()
-            && weeks.is_none()
-            && months.is_none()
-            && years.is_none()
-        {
-            return Err(Error::new(
-                input.span(),
-                "expected [number] followed by one of `minutes`, `hours`, `days`, `years`",
-            ));
-        }
-        Ok(Duration {
-            minutes: minutes.unwrap_or(Number(0)),
-            hours: hours.unwrap_or(Number(0)),
-            days: days.unwrap_or(Number(0)),
-            weeks: weeks.unwrap_or(Number(0)),
-            months: months.unwrap_or(Number(0)),
-            years: years.unwrap_or(Number(0)),
-        })
     }
 }
 
-impl Display for Duration {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut before = false;
-        if self.years > 0 {
-            before = true;
-        }
-        if self.years == 1 {
-            write!(f, "1 year")?;
-        } else if self.years > 1 {
-            write!(f, "{} years", self.years)?;
+impl Parse for TimeRange {
+    fn parse(input: ParseStream) -> Result<Self> {
+        TimeRange::parse_with(input, OfficeHours::default())
+    }
+}
+
+impl TimeRange {
+    /// Parses a [TimeRange] from `s`, using `office_hours` as the bounds of any `office hours`
+    /// / `working hours` range.
+    ///
+    /// The standard [Parse] and [FromStr] impls use [OfficeHours::default], i.e. 9:00 to 17:00.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let office_hours = OfficeHours {
+    ///     start: "8:30".parse().unwrap(),
+    ///     end: "4:30 PM".parse().unwrap(),
+    /// };
+    /// assert_eq!(
+    ///     TimeRange::parse_with_office_hours("office hours on 5/6/2024", office_hours)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "from 5/6/2024 at 8:30 to 5/6/2024 at 4:30 PM"
+    /// );
+    /// ```
+    pub fn parse_with_office_hours(
+        s: &str,
+        office_hours: OfficeHours,
+    ) -> std::result::Result<TimeRange, ParseError> {
+        parse_str_with_offset(s, |input| TimeRange::parse_with(input, office_hours))
+    }
+
+    /// Whether the upcoming tokens start a `rest of the [period]` range.
+    fn peek_rest(input: ParseStream) -> bool {
+        input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident.to_string().to_lowercase() == "rest")
+    }
+
+    /// Whether the upcoming tokens start an `office hours` / `working hours` range.
+    fn peek_office_hours(input: ParseStream) -> bool {
+        let fork = input.fork();
+        let mut ident = fork
+            .parse::<Ident>()
+            .map(|ident| ident.to_string().to_lowercase());
+        if ident.as_ref().is_ok_and(|ident| ident == "during") {
+            ident = fork
+                .parse::<Ident>()
+                .map(|ident| ident.to_string().to_lowercase());
         }
-        if self.months > 0 {
-            if before {
-                write!(f, ", ")?;
+        ident.is_ok_and(|ident| ident == "office" || ident == "working")
+    }
+
+    fn parse_with(input: ParseStream, office_hours: OfficeHours) -> Result<Self> {
+        if TimeRange::peek_office_hours(input) {
+            // (during)? office / working hours (on [date])?
+            let ident = input.parse::<Ident>()?;
+            if ident.to_string().to_lowercase() == "during" {
+                input.parse::<Ident>()?;
             }
-            before = true;
-        }
-        if self.months == 1 {
-            write!(f, "1 month")?;
-        } else if self.months > 1 {
-            write!(f, "{} months", self.months)?;
-        }
-        if self.weeks > 0 {
-            if before {
-                write!(f, ", ")?;
+            let ident = input.parse::<Ident>()?;
+            if ident.to_string().to_lowercase() != "hours" {
+                return Err(Error::new(ident.span(), "expected `hours`"));
             }
-            before = true;
+            return TimeRange::parse_on_date(input, office_hours.start, office_hours.end);
         }
-        if self.weeks == 1 {
-            write!(f, "1 week")?;
-        } else if self.weeks > 1 {
-            write!(f, "{} weeks", self.weeks)?;
+        if TimeRange::peek_date_clock_range(input) {
+            return TimeRange::parse_date_clock_range(input);
         }
-        if self.days > 0 {
-            if before {
-                write!(f, ", ")?;
+        if TimeRange::peek_rest(input) {
+            // rest of (the)? [period]
+            input.parse::<Ident>()?;
+            let of = input.parse::<Ident>()?;
+            if of.to_string().to_lowercase() != "of" {
+                return Err(Error::new(of.span(), "expected `of`"));
             }
-            before = true;
+            let mut period = input.parse::<Ident>()?;
+            if period.to_string().to_lowercase() == "the" {
+                period = input.parse::<Ident>()?;
+            }
+            let end = match period.to_string().to_lowercase().as_str() {
+                "day" => NamedRelativeTime::EndOfDay,
+                "week" => NamedRelativeTime::EndOfWeek,
+                "month" => NamedRelativeTime::EndOfMonth,
+                "year" => NamedRelativeTime::EndOfYear,
+                _ => {
+                    return Err(Error::new(
+                        period.span(),
+                        "expected one of `day`, `week`, `month`, `year`",
+                    ))
+                }
+            };
+            return Ok(TimeRange(
+                PointInTime::Relative(RelativeTime::Named(NamedRelativeTime::Now)),
+                PointInTime::Relative(RelativeTime::Named(end)),
+            ));
         }
-        if self.days == 1 {
-            write!(f, "1 day")?;
-        } else if self.days > 1 {
-            write!(f, "{} days", self.days)?;
+        if TimeRange::peek_period(input) {
+            return TimeRange::parse_period(input);
         }
-        if self.hours > 0 {
-            if before {
-                write!(f, ", ")?;
+        if input.peek(Token![for]) {
+            // for the next / past [duration]
+            input.parse::<Token![for]>()?;
+            if input.peek(Ident) && input.peek2(Ident) {
+                let the = input.fork().parse::<Ident>()?;
+                if the.to_string().to_lowercase() == "the" {
+                    input.parse::<Ident>()?;
+                }
             }
-            before = true;
-        }
-        if self.hours == 1 {
-            write!(f, "1 hour")?;
-        } else if self.hours > 1 {
-            write!(f, "{} hours", self.hours)?;
+            let ident = input.parse::<Ident>()?;
+            let now = PointInTime::Relative(RelativeTime::Named(NamedRelativeTime::Now));
+            return match ident.to_string().to_lowercase().as_str() {
+                "next" => Ok(TimeRange(
+                    now,
+                    PointInTime::Relative(RelativeTime::Directional {
+                        duration: input.parse()?,
+                        dir: TimeDirection::FromNow,
+                    }),
+                )),
+                "past" | "last" => Ok(TimeRange(
+                    PointInTime::Relative(RelativeTime::Directional {
+                        duration: input.parse()?,
+                        dir: TimeDirection::Ago,
+                    }),
+                    now,
+                )),
+                _ => Err(Error::new(
+                    ident.span(),
+                    "expected `next`, `past` or `last`",
+                )),
+            };
         }
-        if self.minutes > 0 {
-            if before {
-                write!(f, ", ")?;
+        let ident = input.parse::<Ident>()?;
+        match ident.to_string().to_lowercase().as_str() {
+            "from" => (),
+            "between" => {
+                // between [point] and [point]
+                let t1 = input.parse::<PointInTime>()?;
+                let and = input.parse::<Ident>()?;
+                if and.to_string().to_lowercase() != "and" {
+                    return Err(Error::new(and.span(), "expected `and`"));
+                }
+                let t2 = input.parse::<PointInTime>()?;
+                return Ok(TimeRange(t1, t2));
+            }
+            _ => {
+                return Err(Error::new(
+                    ident.span(),
+                    "expected `from`, `between` or `for`",
+                ))
             }
         }
-        if self.minutes == 1 {
-            write!(f, "1 minute")?;
-        } else if self.minutes > 1 {
-            write!(f, "{} minutes", self.minutes)?;
+        if TimeRange::peek_clock_range(input) {
+            return TimeRange::parse_clock_range(input);
         }
-        Ok(())
+        let t1 = input.parse::<PointInTime>()?;
+        TimeRange::parse_to(input)?;
+        let t2 = input.parse::<PointInTime>()?;
+        Ok(TimeRange(t1, t2))
     }
 }
 
-/// Represents a specific point in time, which could either be an [AbsoluteTime] (corresponding
-/// with a particular [Date] or [DateTime]), or a [RelativeTime] (corresponding with an offset
-/// from some [AbsoluteTime] or "now").
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
-pub enum PointInTime {
-    /// Based on a specific [Date] or [DateTime] (fixed point) that involves no relative
-    /// indirection, like "3 days after 18/3/2024".
-    Absolute(AbsoluteTime),
-    /// Based on an offset from some known fixed point in time, like "next tuesday".
-    Relative(RelativeTime),
-}
+impl TimeRange {
+    /// Whether the upcoming tokens start a quarter (`Q1 2024`) or ISO week (`week 3 of 2024`).
+    fn peek_period(input: ParseStream) -> bool {
+        let Ok(ident) = input.fork().parse::<Ident>() else {
+            return false;
+        };
+        let ident = ident.to_string().to_lowercase();
+        match ident.strip_prefix('q') {
+            Some(quarter) => !quarter.is_empty() && quarter.bytes().all(|b| b.is_ascii_digit()),
+            None => ident == "week" && input.peek2(LitInt),
+        }
+    }
 
-impl Parse for PointInTime {
-    fn parse(input: ParseStream) -> Result<Self> {
-        if input.peek(LitInt) && input.peek2(Token![/]) {
-            Ok(PointInTime::Absolute(input.parse::<AbsoluteTime>()?))
+    /// Parses `Q[1-4] [Year]` or `week [N] of [Year]` into a range spanning that period, from
+    /// 0:00 on its first day to 23:59 on its last day.
+    fn parse_period(input: ParseStream) -> Result<TimeRange> {
+        let ident = input.parse::<Ident>()?;
+        let (first_day, last_day) = if ident.to_string().to_lowercase() == "week" {
+            let lit = input.parse::<LitInt>()?;
+            let week = lit.base10_parse::<u8>()?;
+            let of = input.parse::<Ident>()?;
+            if of.to_string().to_lowercase() != "of" {
+                return Err(Error::new(of.span(), "expected `of`"));
+            }
+            let year = input.parse::<Year>()?.0 as i64;
+            // ISO week 1 is the week (starting on Monday) containing the 4th of January
+            let week_one = |year: i64| {
+                let jan_4 = days_from_civil(year, 1, 4);
+                jan_4 - weekday(jan_4)
+            };
+            let weeks = (week_one(year + 1) - week_one(year)) / 7;
+            if week == 0 || week as i64 > weeks {
+                return Err(Error::new(
+                    lit.span(),
+                    format!("week must be between 1 and {weeks} (inclusive)"),
+                ));
+            }
+            let first_day = week_one(year) + (week as i64 - 1) * 7;
+            (first_day, first_day + 6)
         } else {
-            Ok(PointInTime::Relative(input.parse::<RelativeTime>()?))
+            let quarter = ident.to_string()[1..].parse::<u8>().unwrap_or(0);
+            if !(1..=4).contains(&quarter) {
+                return Err(Error::new(
+                    ident.span(),
+                    "quarter must be between 1 and 4 (inclusive)",
+                ));
+            }
+            let year = input.parse::<Year>()?.0 as i64;
+            let first_month = quarter * 3 - 2;
+            let next_quarter = match quarter {
+                4 => days_from_civil(year + 1, 1, 1),
+                _ => days_from_civil(year, first_month + 3, 1),
+            };
+            (days_from_civil(year, first_month, 1), next_quarter - 1)
+        };
+        let at = |minutes: i128| {
+            PointInTime::Absolute(AbsoluteTime::DateTime(DateTime::from_minutes(minutes)))
+        };
+        Ok(TimeRange(
+            at(first_day as i128 * MINUTES_PER_DAY),
+            at((last_day + 1) as i128 * MINUTES_PER_DAY - 1),
+        ))
+    }
+
+    /// Parses the `to` (or `through`) separating the endpoints of a range.
+    fn parse_to(input: ParseStream) -> Result<()> {
+        let ident = input.parse::<Ident>()?;
+        if !["to", "through"].contains(&ident.to_string().to_lowercase().as_str()) {
+            return Err(Error::new(ident.span(), "expected `to` or `through`"));
         }
+        Ok(())
     }
-}
 
-impl Display for PointInTime {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            PointInTime::Absolute(abs) => write!(f, "{abs}"),
-            PointInTime::Relative(rel) => write!(f, "{rel}"),
+    /// Whether the upcoming tokens (following `from`) start a range of clock times like `9 to
+    /// 11 AM` or `9:30 AM through 5 PM`, rather than a [PointInTime].
+    fn peek_clock_range(input: ParseStream) -> bool {
+        if TimeRange::peek_clock_keyword(input) {
+            // `noon to ...`, but not `noon tomorrow to ...`
+            let fork = input.fork();
+            fork.parse::<Ident>().ok();
+            return OpenTimeRange::peek_to(&fork);
         }
+        if !input.peek(LitInt) {
+            return false;
+        }
+        if input.peek2(Token![:]) {
+            return true;
+        }
+        let fork = input.fork();
+        fork.parse::<LitInt>().is_ok()
+            && fork.parse::<Ident>().is_ok_and(|ident| {
+                ["to", "through", "am", "pm"].contains(&ident.to_string().to_lowercase().as_str())
+            })
     }
-}
 
-/// Represents an absolute/fixed point in time, such as a [Date] or [DateTime].
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
-pub enum AbsoluteTime {
-    /// A [Date], such as "23/9/2028".
-    Date(Date),
-    /// A [DateTime], such as "28/1/2025 at 5:23 PM" or "1/1/2019 20:15".
-    DateTime(DateTime),
-}
+    /// Whether the upcoming token is one of the time keywords `noon` or `midnight`.
+    fn peek_clock_keyword(input: ParseStream) -> bool {
+        input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| Time::from_keyword(&ident.to_string()).is_some())
+    }
 
-impl Parse for AbsoluteTime {
-    fn parse(input: ParseStream) -> Result<Self> {
+    /// Whether the upcoming tokens are a [Date] followed by `from`, as in `5/6/2024 from noon
+    /// to midnight`.
+    fn peek_date_clock_range(input: ParseStream) -> bool {
         let fork = input.fork();
-        fork.parse::<Date>()?;
-        if (fork.peek(LitInt) && fork.peek2(Token![:]) && fork.peek3(LitInt))
-            || (fork.peek(Ident) && fork.peek2(LitInt) && fork.peek3(Token![:]))
-        {
-            return Ok(AbsoluteTime::DateTime(input.parse()?));
+        Date::peek(&fork)
+            && fork.parse::<Date>().is_ok()
+            && fork
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident.to_string().to_lowercase() == "from")
+    }
+
+    /// Parses a clock time with optional minutes and `AM` / `PM`, e.g. `9`, `9:30` or `11 AM`.
+    fn parse_clock(input: ParseStream) -> Result<(LitInt, Minute, Option<AmPm>)> {
+        Time::reject_duration(input)?;
+        let hour = input.parse::<LitInt>()?;
+        let minute = if input.peek(Token![:]) {
+            input.parse::<Token![:]>()?;
+            Time::reject_duration(input)?;
+            input.parse::<Minute>()?
+        } else {
+            Minute(0)
+        };
+        let am_pm = match AmPm::peek(input) {
+            true => Some(input.parse::<AmPm>()?),
+            false => None,
+        };
+        Ok((hour, minute, am_pm))
+    }
+
+    /// Parses the remainder of `from [clock] to [clock] [AM/PM]? (on [Date])?` after the `from`.
+    fn parse_clock_range(input: ParseStream) -> Result<TimeRange> {
+        let (start, end) = TimeRange::parse_clock_times(input)?;
+        TimeRange::parse_on_date(input, start, end)
+    }
+
+    /// Parses `[Date] from [clock] to [clock]`, producing a range on that date. If the end time
+    /// is earlier than the start time (or is `24:00`), it is taken to be on the following day,
+    /// as in `5/6/2024 from 22:00 to 02:00`.
+    fn parse_date_clock_range(input: ParseStream) -> Result<TimeRange> {
+        let date = input.parse::<Date>()?;
+        let from = input.parse::<Ident>()?;
+        if from.to_string().to_lowercase() != "from" {
+            return Err(Error::new(from.span(), "expected `from`"));
         }
-        Ok(AbsoluteTime::Date(input.parse()?))
+        let (start, end) = TimeRange::parse_clock_times(input)?;
+        let (end, carried) = end.normalize();
+        let end_date = if carried || end.minute_of_day() < start.minute_of_day() {
+            DateTime::from_minutes((date.days() + 1) as i128 * MINUTES_PER_DAY).0
+        } else {
+            date
+        };
+        Ok(TimeRange(
+            PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(date, start))),
+            PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(end_date, end))),
+        ))
     }
-}
 
-impl Display for AbsoluteTime {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AbsoluteTime::Date(date) => write!(f, "{}", date),
-            AbsoluteTime::DateTime(date_time) => write!(f, "{}", date_time),
+    /// Parses `[clock] to [clock] [AM/PM]?`, where either clock may instead be one of the
+    /// keywords `noon` or `midnight`.
+    fn parse_clock_times(input: ParseStream) -> Result<(Time, Time)> {
+        if TimeRange::peek_clock_keyword(input) {
+            let start = input.parse::<Time>()?;
+            TimeRange::parse_to(input)?;
+            return Ok((start, TimeRange::parse_clock_time(input)?));
         }
+        let (start_hour, start_minute, start_am_pm) = TimeRange::parse_clock(input)?;
+        TimeRange::parse_to(input)?;
+        if TimeRange::peek_clock_keyword(input) {
+            let start = TimeRange::clock_time(&start_hour, start_minute, start_am_pm)?;
+            return Ok((start, input.parse::<Time>()?));
+        }
+        let (end_hour, end_minute, end_am_pm) = TimeRange::parse_clock(input)?;
+        // share a single AM / PM between both endpoints, flipping it for the endpoint that
+        // lacks one if the range would otherwise be inverted (e.g. `from 11 to 1 PM`)
+        let flip = |am_pm: AmPm| match am_pm {
+            AmPm::AM => AmPm::PM,
+            AmPm::PM => AmPm::AM,
+        };
+        let time = TimeRange::clock_time;
+        let (start, end) = match (start_am_pm, end_am_pm) {
+            (None, Some(am_pm)) => {
+                let end = time(&end_hour, end_minute, Some(am_pm))?;
+                let mut start = time(&start_hour, start_minute, Some(am_pm))?;
+                if start.minute_of_day() > end.minute_of_day() {
+                    start = time(&start_hour, start_minute, Some(flip(am_pm)))?;
+                }
+                (start, end)
+            }
+            (Some(am_pm), None) => {
+                let start = time(&start_hour, start_minute, Some(am_pm))?;
+                let mut end = time(&end_hour, end_minute, Some(am_pm))?;
+                if end.minute_of_day() < start.minute_of_day() {
+                    end = time(&end_hour, end_minute, Some(flip(am_pm)))?;
+                }
+                (start, end)
+            }
+            (start_am_pm, end_am_pm) => (
+                time(&start_hour, start_minute, start_am_pm)?,
+                time(&end_hour, end_minute, end_am_pm)?,
+            ),
+        };
+        Ok((start, end))
     }
-}
 
-/// Combined with "next" or "after" to denote specific [RelativeTime]s.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+    /// Parses a single clock time (or time keyword), treating it as 24-hour unless `AM` / `PM`
+    /// is given.
+    fn parse_clock_time(input: ParseStream) -> Result<Time> {
+        if TimeRange::peek_clock_keyword(input) {
+            return input.parse::<Time>();
+        }
+        let (hour, minute, am_pm) = TimeRange::parse_clock(input)?;
+        TimeRange::clock_time(&hour, minute, am_pm)
+    }
+
+    /// Validates the components of a clock time parsed by [TimeRange::parse_clock].
+    fn clock_time(hour: &LitInt, minute: Minute, am_pm: Option<AmPm>) -> Result<Time> {
+        let hour_val = hour.base10_parse::<u8>()?;
+        match am_pm {
+            Some(am_pm) => {
+                if hour_val > 12 || hour_val == 0 {
+                    return Err(Error::new(
+                        hour.span(),
+                        "hour must be between 1 and 12 (inclusive)",
+                    ));
+                }
+                Ok(Time(Hour::Hour12(hour_val, am_pm), minute))
+            }
+            None => {
+                if hour_val > 24 {
+                    return Err(Error::new(
+                        hour.span(),
+                        "hour must be between 0 and 24 (inclusive)",
+                    ));
+                }
+                if hour_val == 24 && minute.0 != 0 {
+                    return Err(Error::new(hour.span(), "minute must be 00 when hour is 24"));
+                }
+                Ok(Time(Hour::Hour24(hour_val), minute))
+            }
+        }
+    }
+
+    /// Parses an optional `on [Date]` suffix, producing a range from `start` to `end` on that
+    /// date, or today if no date is given.
+    fn parse_on_date(input: ParseStream, start: Time, end: Time) -> Result<TimeRange> {
+        let fork = input.fork();
+        if fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident.to_string().to_lowercase() == "on")
+        {
+            input.parse::<Ident>()?;
+            let date = input.parse::<Date>()?;
+            return Ok(TimeRange(
+                PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(date, start))),
+                PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(date, end))),
+            ));
+        }
+        let today = |time: Time| {
+            PointInTime::Relative(RelativeTime::NamedAt(NamedRelativeTime::Today, time.into()))
+        };
+        Ok(TimeRange(today(start), today(end)))
+    }
+
+    /// Constructs a [TimeRange], verifying that `start` does not come after `end` when both
+    /// endpoints are [PointInTime::Absolute]. Ranges with a relative endpoint cannot be checked
+    /// without a reference "now" and are always accepted.
+    ///
+    /// Note that parsing is more tolerant and accepts inverted ranges as written.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let start = "1/1/2023".parse::<PointInTime>().unwrap();
+    /// let end = "15/1/2023".parse::<PointInTime>().unwrap();
+    /// assert!(TimeRange::new(start, end).is_ok());
+    /// assert!(TimeRange::new(end, start).is_err());
+    /// ```
+    pub fn new(start: PointInTime, end: PointInTime) -> std::result::Result<TimeRange, RangeError> {
+        if let (PointInTime::Absolute(start), PointInTime::Absolute(end)) = (start, end) {
+            if start.date_time().minutes() > end.date_time().minutes() {
+                return Err(RangeError { start, end });
+            }
+        }
+        Ok(TimeRange(start, end))
+    }
+
+    /// The start of this [TimeRange] as an [AbsoluteTime], resolving it against `now` if it is
+    /// relative. See [PointInTime::to_absolute].
+    pub fn start_absolute(&self, now: DateTime) -> AbsoluteTime {
+        self.0.to_absolute(now)
+    }
+
+    /// The end of this [TimeRange] as an [AbsoluteTime], resolving it against `now` if it is
+    /// relative. See [PointInTime::to_absolute].
+    pub fn end_absolute(&self, now: DateTime) -> AbsoluteTime {
+        self.1.to_absolute(now)
+    }
+
+    /// Iterates over each [Date] covered by this [TimeRange], from the date of its earliest
+    /// endpoint to the date of its latest endpoint (inclusive), resolving any relative endpoints
+    /// against `now`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let range = "from today to the day after tomorrow".parse::<TimeRange>().unwrap();
+    /// assert_eq!(range.iter_days(now).count(), 3);
+    /// ```
+    pub fn iter_days(&self, now: DateTime) -> impl Iterator<Item = Date> {
+        let (start, end) = self.resolved_bounds(now);
+        (start.div_euclid(MINUTES_PER_DAY)..=end.div_euclid(MINUTES_PER_DAY))
+            .map(|days| DateTime::from_minutes(days * MINUTES_PER_DAY).0)
+    }
+
+    /// Iterates over this [TimeRange] in increments of `step`, starting at its earliest
+    /// endpoint and yielding each [DateTime] that does not exceed its latest endpoint, resolving
+    /// any relative endpoints against `now`.
+    ///
+    /// Each step is applied to the previously yielded [DateTime] (as in [DateTime] + [Duration]),
+    /// so month and year steps that clamp the day of the month (e.g. 31/1 + 1 month = 29/2)
+    /// carry that clamping forward. A zero `step` yields only the start of the range.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let range = "from 1/1/2023 to 1/1/2023 at 12:00".parse::<TimeRange>().unwrap();
+    /// let step = "4 hours".parse::<Duration>().unwrap();
+    /// assert_eq!(range.iter_step(step, now).count(), 4);
+    /// ```
+    pub fn iter_step(&self, step: Duration, now: DateTime) -> impl Iterator<Item = DateTime> {
+        let (start, end) = self.resolved_bounds(now);
+        let mut next = Some(DateTime::from_minutes(start));
+        std::iter::from_fn(move || {
+            let current = next?;
+            let shifted = current + step;
+            // stop rather than looping forever on a zero (or saturated) step
+            next = (shifted.minutes() > current.minutes()).then_some(shifted);
+            Some(current)
+        })
+        .take_while(move |date_time| date_time.minutes() <= end)
+    }
+
+    /// The resolved endpoints of this [TimeRange] in minutes since the epoch, earliest first.
+    fn resolved_bounds(&self, now: DateTime) -> (i128, i128) {
+        let start = self.0.resolve(now).minutes();
+        let end = self.1.resolve(now).minutes();
+        (start.min(end), start.max(end))
+    }
+
+    /// Returns the amount of time spanned by this [TimeRange] once its endpoints are resolved
+    /// against `now`, regardless of the order in which they were written. See
+    /// [AbsoluteTime::duration_until] for how the [Duration] is computed.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let range = "from 1/1/2023 to 15/1/2023".parse::<TimeRange>().unwrap();
+    /// assert_eq!(range.span(now), Duration::builder().days(14).build().normalize());
+    /// let range = "from 3 hours ago to tomorrow".parse::<TimeRange>().unwrap();
+    /// assert_eq!(range.span(now).to_string(), "16 hours, 30 minutes");
+    /// ```
+    pub fn span(&self, now: DateTime) -> Duration {
+        let (start, end) = self.resolved_bounds(now);
+        Duration::between_minutes(end - start)
+    }
+
+    /// Returns `true` if this [TimeRange] and `other` cover the same interval once their
+    /// endpoints are resolved against `now`, regardless of the order in which the endpoints
+    /// were written. Unlike the derived [PartialEq] impl, `from A to B` and `from B to A` are
+    /// considered the same interval.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "1/6/2024 at 12:00".parse::<DateTime>().unwrap();
+    /// let a = "from 1/1/2024 to tomorrow".parse::<TimeRange>().unwrap();
+    /// let b = "from 2/6/2024 to 1/1/2024 at 0:00".parse::<TimeRange>().unwrap();
+    /// assert!(a.same_interval(&b, now));
+    /// assert_ne!(a, b);
+    /// ```
+    pub fn same_interval(&self, other: &TimeRange, now: DateTime) -> bool {
+        self.resolved_bounds(now) == other.resolved_bounds(now)
+    }
+
+    /// Returns `true` if this [TimeRange] and `other` touch exactly, i.e. the end of one is the
+    /// start of the other, once their endpoints are resolved against `now` (endpoints written
+    /// in reverse order are treated as if they were swapped).
+    ///
+    /// Touching is not overlapping: touching ranges share only the boundary instant, so ranges
+    /// that overlap by even a minute, or that are separated by a gap, do not touch. A
+    /// zero-width range touches a range starting or ending at that instant.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "1/6/2024 at 12:00".parse::<DateTime>().unwrap();
+    /// let morning = "from 1/6/2024 at 9:00 to 1/6/2024 at 12:00".parse::<TimeRange>().unwrap();
+    /// let afternoon = "from now to 1/6/2024 at 17:00".parse::<TimeRange>().unwrap();
+    /// assert!(morning.touches(&afternoon, now));
+    /// assert!(afternoon.touches(&morning, now));
+    /// ```
+    pub fn touches(&self, other: &TimeRange, now: DateTime) -> bool {
+        let (start, end) = self.resolved_bounds(now);
+        let (other_start, other_end) = other.resolved_bounds(now);
+        end == other_start || other_end == start
+    }
+
+    /// Returns `true` if `point` lies within this [TimeRange], resolving any relative endpoints
+    /// against `now`. The interval is closed, i.e. both endpoints are included, and endpoints
+    /// written in reverse order are treated as if they were swapped.
+    ///
+    /// Note that a [Date] endpoint or point stands for midnight at the start of that day, so
+    /// "from 1/1/2023 to 15/1/2023" does not contain `15/1/2023 at 10:00`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "1/6/2024 at 12:00".parse::<DateTime>().unwrap();
+    /// let range = "from 1/1/2023 to 15/1/2023".parse::<TimeRange>().unwrap();
+    /// assert!(range.contains(&"7/1/2023 at 9:00 AM".parse().unwrap(), now));
+    /// assert!(range.contains(&"15/1/2023".parse().unwrap(), now));
+    /// assert!(!range.contains(&"16/1/2023".parse().unwrap(), now));
+    /// ```
+    pub fn contains(&self, point: &AbsoluteTime, now: DateTime) -> bool {
+        let (start, end) = self.resolved_bounds(now);
+        (start..=end).contains(&point.date_time().minutes())
+    }
+}
+
+impl Display for TimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use NamedRelativeTime::*;
+        if let TimeRange(
+            PointInTime::Relative(RelativeTime::Named(Now)),
+            PointInTime::Relative(RelativeTime::Named(end)),
+        ) = self
+        {
+            let period = match end {
+                EndOfDay => Some("day"),
+                EndOfWeek => Some("week"),
+                EndOfMonth => Some("month"),
+                EndOfYear => Some("year"),
+                _ => None,
+            };
+            if let Some(period) = period {
+                return write!(f, "rest of the {period}");
+            }
+        }
+        write!(f, "from {} to {}", self.0, self.1)
+    }
+}
+
+/// Returned by [TimeRange::new] when the end of a range precedes its start.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct RangeError {
+    /// The start of the rejected range.
+    pub start: AbsoluteTime,
+    /// The end of the rejected range, which comes before `start`.
+    pub end: AbsoluteTime,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "range end {} precedes range start {}",
+            self.end, self.start
+        )
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// Represents a range of time with only one bound, like "from 1/1/2024" or "to 1/1/2025". The
+/// missing bound extends infinitely into the future or past, respectively.
+///
+/// `after X` is also accepted in place of `from X`, and `until X` / `before X` in place of
+/// `to X`. These are displayed using the canonical `from` / `to` forms.
+///
+/// ```
+/// use timelang::*;
+/// let now = "1/6/2024 at 12:00".parse::<DateTime>().unwrap();
+/// let range = "from 1/1/2024".parse::<OpenTimeRange>().unwrap();
+/// assert!(range.contains(&"31/12/9999".parse().unwrap(), now));
+/// assert!(!range.contains(&"31/12/2023".parse().unwrap(), now));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OpenTimeRange {
+    /// Bounded at the start only, i.e. `from X` or `after X`.
+    From(PointInTime),
+    /// Bounded at the end only, i.e. `to X`, `until X` or `before X`.
+    To(PointInTime),
+}
+
+impl OpenTimeRange {
+    /// Whether the next token is the `to` (or `through`) keyword.
+    fn peek_to(input: ParseStream) -> bool {
+        input.fork().parse::<Ident>().is_ok_and(|ident| {
+            ["to", "through"].contains(&ident.to_string().to_lowercase().as_str())
+        })
+    }
+
+    /// Returns `true` if `point` lies within this [OpenTimeRange], resolving the bound against
+    /// `now`. The bound itself is included, and the missing bound is treated as infinitely far
+    /// in the past or future.
+    pub fn contains(&self, point: &AbsoluteTime, now: DateTime) -> bool {
+        let point = point.date_time().minutes();
+        match self {
+            OpenTimeRange::From(start) => point >= start.resolve(now).minutes(),
+            OpenTimeRange::To(end) => point <= end.resolve(now).minutes(),
+        }
+    }
+}
+
+impl Parse for OpenTimeRange {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let ident = input.parse::<Ident>()?;
+        match ident.to_string().to_lowercase().as_str() {
+            "from" | "after" => Ok(OpenTimeRange::From(input.parse()?)),
+            "to" | "until" | "before" => Ok(OpenTimeRange::To(input.parse()?)),
+            _ => Err(Error::new(
+                ident.span(),
+                "expected one of `from`, `after`, `to`, `until`, `before`",
+            )),
+        }
+    }
+}
+
+impl Display for OpenTimeRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpenTimeRange::From(start) => write!(f, "from {start}"),
+            OpenTimeRange::To(end) => write!(f, "to {end}"),
+        }
+    }
+}
+
+/// Represents a specific duration of time that is not anchored at any particular point in time.
+///
+/// Note that individual components, if not specified, will be recorded as `0`. Such components
+/// will not appear when the [Duration] is rendered, printed, or displayed.
+///
+/// Components may be separated by nothing, `,`, `and` or `, and`, in any mix, as in "1 day 2
+/// hours, 3 weeks and 4 minutes". An `and` must be followed by another component, while a
+/// trailing comma is allowed, as in "2 hours, ago".
+///
+/// Quantities may also be spelled out as `a` / `an` or as a number word from `one` to
+/// `nineteen`, as in "two days" or "an hour". Larger spelled-out numbers are not accepted.
+///
+/// The derived [Ord] impl is field-wise rather than magnitude-based; see
+/// [Duration::total_minutes] for a scalar suitable for sorting by length.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Duration {
+    /// The number of minutes.
+    pub minutes: Number,
+    /// The number of hours.
+    pub hours: Number,
+    /// The number of days.
+    pub days: Number,
+    /// The number of weeks.
+    pub weeks: Number,
+    /// The number of months.
+    pub months: Number,
+    /// The number of years.
+    pub years: Number,
+}
+
+impl Parse for Duration {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Duration::parse_bounded(input, Duration::DEFAULT_MAX_MINUTES, false)
+    }
+}
+
+impl Duration {
+    /// The default upper bound on the total length (in minutes) of a parsed [Duration]: the
+    /// span of the entire range representable by [Year] (65,536 years, approximating years as
+    /// 365 days). Any longer duration would carry every [DateTime] out of range, so inputs like
+    /// "999999999 years" are rejected at parse time.
+    pub const DEFAULT_MAX_MINUTES: u64 = (u16::MAX as u64 + 1) * 365 * 24 * 60;
+
+    /// Parses a [Duration] from `s`, failing with an error if its total length exceeds
+    /// `max_minutes`, where months and years are approximated as 30 and 365 days,
+    /// respectively.
+    ///
+    /// The standard [Parse] and [FromStr] impls use [Duration::DEFAULT_MAX_MINUTES].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert!(Duration::parse_with_max_minutes("1 hour", 60).is_ok());
+    /// assert!(Duration::parse_with_max_minutes("1 hour, 1 minute", 60).is_err());
+    /// ```
+    pub fn parse_with_max_minutes(
+        s: &str,
+        max_minutes: u64,
+    ) -> std::result::Result<Duration, ParseError> {
+        parse_str_with_offset(s, |input| {
+            Duration::parse_bounded(input, max_minutes, false)
+        })
+    }
+
+    /// Parses a [Duration] from `s` like the [FromStr] impl, except that each [TimeUnit] may
+    /// appear at most once. Whereas "2 hours, 3 hours" normally accumulates to 5 hours, here it
+    /// fails with an error positioned at the second occurrence of the unit. Note that
+    /// "fortnight" counts as weeks, and "half an hour" / "quarter of an hour" as minutes.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert!(Duration::parse_strict("2 hours, 30 minutes").is_ok());
+    /// let err = Duration::parse_strict("2 hours, 3 hours").unwrap_err();
+    /// assert_eq!(err.offset, 9);
+    /// ```
+    pub fn parse_strict(s: &str) -> std::result::Result<Duration, ParseError> {
+        parse_str_with_offset(s, |input| {
+            Duration::parse_bounded(input, Duration::DEFAULT_MAX_MINUTES, true)
+        })
+    }
+
+    /// Parses a [Duration] whose total length does not exceed `max_minutes`, rejecting repeated
+    /// units if `strict` is `true` (and accumulating them otherwise).
+    fn parse_bounded(input: ParseStream, max_minutes: u64, strict: bool) -> Result<Self> {
+        let mut duration = Duration {
+            minutes: Number(0),
+            hours: Number(0),
+            days: Number(0),
+            weeks: Number(0),
+            months: Number(0),
+            years: Number(0),
+        };
+        let mut empty = true;
+        let mut seen = Vec::new();
+        while Duration::peek_quantity(input) {
+            let span = input.span();
+            let too_large = || {
+                Error::new(
+                    span,
+                    format!("duration must not exceed {max_minutes} minutes"),
+                )
+            };
+            let component = |input: ParseStream| -> Result<(Number, TimeUnit)> {
+                if Duration::parse_fraction(&input.fork()).is_ok() {
+                    return Ok((Number(Duration::parse_fraction(input)?), TimeUnit::Minutes));
+                }
+                let num = if input.peek(LitInt) {
+                    input.parse::<Number>()?
+                } else {
+                    // `a` / `an`, or a number word
+                    let word = input.parse::<Ident>()?.to_string().to_lowercase();
+                    Number(Number::from_word(&word).unwrap_or(1))
+                };
+                let (multiplier, unit) = Duration::parse_unit(input)?;
+                Ok((
+                    Number(num.0.checked_mul(multiplier).ok_or_else(too_large)?),
+                    unit,
+                ))
+            };
+            if strict {
+                // check before consuming, so that errors point at the repeated unit
+                let (_, unit) = component(&input.fork())?;
+                if seen.contains(&unit) {
+                    return Err(Error::new(
+                        span,
+                        format!("duplicate `{}` in duration", unit.label(2)),
+                    ));
+                }
+                seen.push(unit);
+            }
+            let (num, unit) = component(input)?;
+            let mut updated = duration;
+            let total = updated
+                .get(unit)
+                .0
+                .checked_add(num.0)
+                .ok_or_else(too_large)?;
+            updated.set(unit, Number(total));
+            if updated.approximate_minutes() > max_minutes as u128 {
+                return Err(too_large());
+            }
+            duration = updated;
+            empty = false;
+            Duration::parse_separator(input)?;
+        }
+        if empty {
+            return Err(Error::new(
+                input.span(),
+                "expected [number] followed by one of `minutes`, `hours`, `days`, `years`",
+            ));
+        }
+        Ok(duration)
+    }
+}
+
+impl Duration {
+    /// Parses an optional separator after a component of a [Duration], i.e. nothing, `,`,
+    /// `and` or `, and`. A comma is always consumed (as in "2 hours, ago"), whereas `and` is
+    /// only consumed when another quantity follows it.
+    fn parse_separator(input: ParseStream) -> Result<()> {
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+        let fork = input.fork();
+        let and = fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident.to_string().to_lowercase() == "and");
+        if and && Duration::peek_quantity(&fork) {
+            input.parse::<Ident>()?;
+        }
+        Ok(())
+    }
+
+    /// Whether the upcoming tokens start a `[number] [unit]` pair, where `a` / `an` count as
+    /// the number 1 and number words as their value when followed by a [TimeUnit] (as in "a
+    /// day", "an hour" or "two weeks").
+    fn peek_quantity(input: ParseStream) -> bool {
+        if input.peek(LitInt) {
+            return true;
+        }
+        let fork = input.fork();
+        if fork.parse::<Ident>().is_ok_and(|ident| {
+            let word = ident.to_string().to_lowercase();
+            matches!(word.as_str(), "a" | "an") || Number::from_word(&word).is_some()
+        }) && Duration::parse_unit(&fork).is_ok()
+        {
+            return true;
+        }
+        Duration::parse_fraction(&input.fork()).is_ok()
+    }
+
+    /// An upper bound on the [Display] length of this [Duration].
+    fn len_hint(&self) -> usize {
+        [
+            self.minutes,
+            self.hours,
+            self.days,
+            self.weeks,
+            self.months,
+            self.years,
+        ]
+        .iter()
+        .filter(|count| **count != 0)
+        .map(|count| {
+            let digits = count.0.checked_ilog10().unwrap_or(0) as usize + 1;
+            ", ".len() + digits + 1 + "minutes".len()
+        })
+        .sum()
+    }
+
+    /// Parses a fraction of an hour, i.e. `(a)? half (a | an)? hour` (30 minutes) or
+    /// `(a)? quarter (of)? (a | an)? hour` (15 minutes), returning the number of minutes.
+    fn parse_fraction(input: ParseStream) -> Result<u64> {
+        let next = || {
+            input
+                .parse::<Ident>()
+                .map(|ident| (ident.to_string().to_lowercase(), ident.span()))
+        };
+        let (mut word, mut span) = next()?;
+        if word == "a" {
+            (word, span) = next()?;
+        }
+        let minutes = match word.as_str() {
+            "half" => 30,
+            "quarter" => 15,
+            _ => return Err(Error::new(span, "expected `half` or `quarter`")),
+        };
+        (word, span) = next()?;
+        if minutes == 15 && word == "of" {
+            (word, span) = next()?;
+        }
+        if word == "a" || word == "an" {
+            (word, span) = next()?;
+        }
+        match word.as_str() {
+            "hour" | "hr" => Ok(minutes),
+            _ => Err(Error::new(span, "expected `hour`")),
+        }
+    }
+
+    /// Parses a [TimeUnit], or a `fortnight` (which counts as 2 weeks), returning the unit
+    /// along with the multiplier to apply to the preceding quantity.
+    fn parse_unit(input: ParseStream) -> Result<(u64, TimeUnit)> {
+        let fork = input.fork();
+        if fork.parse::<Ident>().is_ok_and(|ident| {
+            matches!(
+                ident.to_string().to_lowercase().as_str(),
+                "fortnight" | "fortnights"
+            )
+        }) {
+            input.parse::<Ident>()?;
+            return Ok((2, TimeUnit::Weeks));
+        }
+        Ok((1, input.parse::<TimeUnit>()?))
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut before = false;
+        for (count, unit) in [
+            (self.years, TimeUnit::Years),
+            (self.months, TimeUnit::Months),
+            (self.weeks, TimeUnit::Weeks),
+            (self.days, TimeUnit::Days),
+            (self.hours, TimeUnit::Hours),
+            (self.minutes, TimeUnit::Minutes),
+        ] {
+            if count == 0 {
+                continue;
+            }
+            if before {
+                write!(f, ", ")?;
+            }
+            write!(f, "{} {}", count, unit.label(count.0))?;
+            before = true;
+        }
+        Ok(())
+    }
+}
+
+/// A builder for [Duration]s, created via [Duration::builder].
+///
+/// Each method adds to the corresponding component (saturating at [u64::MAX]), mirroring how
+/// the parser accumulates repeated units like "1 day and 2 days". Components that are never
+/// specified default to zero.
+///
+/// ```
+/// use timelang::*;
+/// let duration = Duration::builder().hours(2).minutes(30).build();
+/// assert_eq!(duration.to_string(), "2 hours, 30 minutes");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct DurationBuilder(Duration);
+
+impl DurationBuilder {
+    /// Adds `n` years.
+    pub fn years(mut self, n: u64) -> Self {
+        self.0.years = Number(self.0.years.0.saturating_add(n));
+        self
+    }
+
+    /// Adds `n` months.
+    pub fn months(mut self, n: u64) -> Self {
+        self.0.months = Number(self.0.months.0.saturating_add(n));
+        self
+    }
+
+    /// Adds `n` weeks.
+    pub fn weeks(mut self, n: u64) -> Self {
+        self.0.weeks = Number(self.0.weeks.0.saturating_add(n));
+        self
+    }
+
+    /// Adds `n` days.
+    pub fn days(mut self, n: u64) -> Self {
+        self.0.days = Number(self.0.days.0.saturating_add(n));
+        self
+    }
+
+    /// Adds `n` hours.
+    pub fn hours(mut self, n: u64) -> Self {
+        self.0.hours = Number(self.0.hours.0.saturating_add(n));
+        self
+    }
+
+    /// Adds `n` minutes.
+    pub fn minutes(mut self, n: u64) -> Self {
+        self.0.minutes = Number(self.0.minutes.0.saturating_add(n));
+        self
+    }
+
+    /// Returns the [Duration] built so far.
+    pub fn build(self) -> Duration {
+        self.0
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+
+    /// Adds each component pairwise, saturating at [u64::MAX].
+    fn add(self, rhs: Self) -> Self::Output {
+        Duration {
+            minutes: self.minutes.saturating_add(rhs.minutes),
+            hours: self.hours.saturating_add(rhs.hours),
+            days: self.days.saturating_add(rhs.days),
+            weeks: self.weeks.saturating_add(rhs.weeks),
+            months: self.months.saturating_add(rhs.months),
+            years: self.years.saturating_add(rhs.years),
+        }
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    /// Subtracts each component pairwise, saturating at zero. Note that no borrowing between
+    /// units takes place, so `1 hour - 30 minutes` is `1 hour`.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Duration {
+            minutes: self.minutes.saturating_sub(rhs.minutes),
+            hours: self.hours.saturating_sub(rhs.hours),
+            days: self.days.saturating_sub(rhs.days),
+            weeks: self.weeks.saturating_sub(rhs.weeks),
+            months: self.months.saturating_sub(rhs.months),
+            years: self.years.saturating_sub(rhs.years),
+        }
+    }
+}
+
+impl Mul<u64> for Duration {
+    type Output = Duration;
+
+    /// Multiplies each component by `rhs`, saturating at [u64::MAX]. No normalization takes
+    /// place, so `(2 hours, 30 minutes) * 2` is `4 hours, 60 minutes`.
+    fn mul(self, rhs: u64) -> Self::Output {
+        let mul = |n: Number| Number(n.0.saturating_mul(rhs));
+        Duration {
+            minutes: mul(self.minutes),
+            hours: mul(self.hours),
+            days: mul(self.days),
+            weeks: mul(self.weeks),
+            months: mul(self.months),
+            years: mul(self.years),
+        }
+    }
+}
+
+impl Mul<Duration> for u64 {
+    type Output = Duration;
+
+    /// Multiplies each component of `rhs` by `self`, saturating at [u64::MAX].
+    fn mul(self, rhs: Duration) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// Wraps a [Duration] so that it compares and hashes by its total length (see
+/// [Duration::total_minutes]) rather than field by field, making it suitable as a key in
+/// hash-based collections where `1 hour` and `60 minutes` should collide.
+///
+/// Since [Duration::total_minutes] approximates months as 30 days and years as 365 days,
+/// `1 month` and `30 days` are also considered equal here. The wrapped [Duration] keeps its
+/// original spelling.
+///
+/// ```
+/// use timelang::*;
+/// let hour: NormalizedDuration = "1 hour".parse::<Duration>().unwrap().into();
+/// let minutes: NormalizedDuration = "60 minutes".parse::<Duration>().unwrap().into();
+/// assert_eq!(hour, minutes);
+/// assert_ne!(hour.0, minutes.0);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct NormalizedDuration(pub Duration);
+
+impl From<Duration> for NormalizedDuration {
+    fn from(value: Duration) -> Self {
+        NormalizedDuration(value)
+    }
+}
+
+impl PartialEq for NormalizedDuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_minutes() == other.0.total_minutes()
+    }
+}
+
+impl Eq for NormalizedDuration {}
+
+impl std::hash::Hash for NormalizedDuration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.total_minutes().hash(state);
+    }
+}
+
+/// A [Duration] with a sign, for representing offsets such as `-3 days`.
+///
+/// A [SignedDuration] is parsed as a [Duration] optionally preceded by `-` or the word `minus`,
+/// either of which makes it negative. It is always displayed with a leading `-` when
+/// negative. Plain [Duration]s remain unsigned, and do not accept either prefix.
+///
+/// ```
+/// use timelang::*;
+/// let offset = "minus 2 hours".parse::<SignedDuration>().unwrap();
+/// assert!(offset.negative);
+/// assert_eq!(offset.duration, Duration::builder().hours(2).build());
+/// assert_eq!(offset.to_string(), "-2 hours");
+/// assert_eq!("3 days".parse::<SignedDuration>().unwrap().to_string(), "3 days");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedDuration {
+    /// Whether this offset points backwards in time.
+    pub negative: bool,
+    /// The magnitude of this offset.
+    pub duration: Duration,
+}
+
+impl Parse for SignedDuration {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let negative = if input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+            true
+        } else if input.peek(Ident) && !Duration::peek_quantity(input) {
+            let ident = input.parse::<Ident>()?;
+            if ident.to_string().to_lowercase() != "minus" {
+                return Err(Error::new(
+                    ident.span(),
+                    "expected `-`, `minus` or [duration]",
+                ));
+            }
+            true
+        } else {
+            false
+        };
+        Ok(SignedDuration {
+            negative,
+            duration: input.parse()?,
+        })
+    }
+}
+
+impl Display for SignedDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", self.duration)
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(duration: Duration) -> Self {
+        SignedDuration {
+            negative: false,
+            duration,
+        }
+    }
+}
+
+impl Duration {
+    /// The total number of minutes in this [Duration], approximating months as 30 days and
+    /// years as 365 days.
+    fn approximate_minutes(&self) -> u128 {
+        let days = self.years.0 as u128 * 365
+            + self.months.0 as u128 * 30
+            + self.weeks.0 as u128 * 7
+            + self.days.0 as u128;
+        (days * 24 + self.hours.0 as u128) * 60 + self.minutes.0 as u128
+    }
+
+    /// Returns a [DurationBuilder] with all components set to zero.
+    pub fn builder() -> DurationBuilder {
+        DurationBuilder(Duration {
+            minutes: Number(0),
+            hours: Number(0),
+            days: Number(0),
+            weeks: Number(0),
+            months: Number(0),
+            years: Number(0),
+        })
+    }
+
+    /// Returns `true` if all components of this [Duration] are zero. Such a [Duration]
+    /// displays as an empty string; see [Duration::to_string_nonempty].
+    pub fn is_zero(&self) -> bool {
+        [
+            self.minutes,
+            self.hours,
+            self.days,
+            self.weeks,
+            self.months,
+            self.years,
+        ]
+        .iter()
+        .all(|count| *count == 0)
+    }
+
+    /// Like [ToString::to_string], but renders a zero [Duration] (see [Duration::is_zero]) as
+    /// `0 minutes` rather than an empty string.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(Duration::builder().build().to_string_nonempty(), "0 minutes");
+    /// assert_eq!(Duration::builder().days(2).build().to_string_nonempty(), "2 days");
+    /// ```
+    pub fn to_string_nonempty(&self) -> String {
+        if self.is_zero() {
+            return format!("0 {}", TimeUnit::Minutes.label(0));
+        }
+        self.to_string()
+    }
+
+    /// Renders this [Duration] in English words, e.g. `two hours and thirty minutes` rather
+    /// than `2 hours, 30 minutes`, for text-to-speech and similar output. All but the last two
+    /// components are separated by commas, and the last two are joined with `and`. A zero
+    /// [Duration] is rendered as `zero minutes`.
+    ///
+    /// The parser only accepts the number words `one` to `nineteen`, so the output parses back
+    /// only when every non-zero component is below 20. Larger numbers spelled out here, such as
+    /// `thirty`, do not parse.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let duration = "1 day, 2 hours, 30 minutes".parse::<Duration>().unwrap();
+    /// assert_eq!(duration.to_words(), "one day, two hours and thirty minutes");
+    /// assert_eq!("21 weeks".parse::<Duration>().unwrap().to_words(), "twenty-one weeks");
+    /// let small = "1 day, 2 hours, 3 minutes".parse::<Duration>().unwrap();
+    /// assert_eq!(small.to_words().parse::<Duration>().unwrap(), small);
+    /// assert!(duration.to_words().parse::<Duration>().is_err());
+    /// ```
+    pub fn to_words(&self) -> String {
+        let parts = [
+            (self.years, TimeUnit::Years),
+            (self.months, TimeUnit::Months),
+            (self.weeks, TimeUnit::Weeks),
+            (self.days, TimeUnit::Days),
+            (self.hours, TimeUnit::Hours),
+            (self.minutes, TimeUnit::Minutes),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count != 0)
+        .map(|(count, unit)| format!("{} {}", count.to_words(), unit.label(count.0)))
+        .collect::<Vec<_>>();
+        match parts.as_slice() {
+            [] => format!("zero {}", TimeUnit::Minutes.label(0)),
+            [part] => part.clone(),
+            [init @ .., last] => format!("{} and {last}", init.join(", ")),
+        }
+    }
+
+    /// Carries overflowing units into larger ones, so that `90 minutes` becomes
+    /// `1 hour, 30 minutes` and `36 hours` becomes `1 day, 12 hours`.
+    ///
+    /// 60 minutes carry into an hour, 24 hours into a day, 7 days into a week and 12 months
+    /// into a year. Since a month is not a whole number of weeks, weeks are never carried into
+    /// months here; see [Duration::normalize_with] to opt into that. Years saturate at
+    /// [u64::MAX].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let duration = "90 minutes".parse::<Duration>().unwrap();
+    /// assert_eq!(duration.normalize().to_string(), "1 hour, 30 minutes");
+    /// ```
+    pub fn normalize(&self) -> Duration {
+        self.normalize_with(false)
+    }
+
+    /// Returns `true` if this [Duration] and `other` are equal once both are normalized (see
+    /// [Duration::normalize]), so that e.g. `60 minutes` and `1 hour` compare equal. The
+    /// derived [PartialEq] impl (and therefore [Hash]) remains field-exact.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let a = "90 minutes".parse::<Duration>().unwrap();
+    /// let b = "1 hour, 30 minutes".parse::<Duration>().unwrap();
+    /// assert_ne!(a, b);
+    /// assert!(a.eq_normalized(&b));
+    /// ```
+    pub fn eq_normalized(&self, other: &Self) -> bool {
+        self.normalize() == other.normalize()
+    }
+
+    /// Like [Duration::normalize], but if `weeks_to_months` is `true`, weeks and days are
+    /// additionally carried into months using the 30-day month convention, i.e. `5 weeks`
+    /// becomes `1 month, 5 days`.
+    pub fn normalize_with(&self, weeks_to_months: bool) -> Duration {
+        let minutes = self.minutes.0 as u128;
+        let hours = self.hours.0 as u128 + minutes / 60;
+        let mut days = self.days.0 as u128 + hours / 24;
+        let mut weeks = self.weeks.0 as u128 + days / 7;
+        let mut months = self.months.0 as u128;
+        if weeks_to_months {
+            let total_days = self.weeks.0 as u128 * 7 + days;
+            months += total_days / 30;
+            weeks = total_days % 30 / 7;
+            days = total_days % 30;
+        }
+        let years = self.years.0 as u128 + months / 12;
+        let clamp = |value: u128| Number(u64::try_from(value).unwrap_or(u64::MAX));
+        Duration {
+            minutes: clamp(minutes % 60),
+            hours: clamp(hours % 24),
+            days: clamp(days % 7),
+            weeks: clamp(weeks),
+            months: clamp(months % 12),
+            years: clamp(years),
+        }
+    }
+
+    /// A normalized [Duration] spanning the given (possibly negative) difference in minutes,
+    /// saturating at zero.
+    fn between_minutes(minutes: i128) -> Duration {
+        let minutes = u64::try_from(minutes.max(0)).unwrap_or(u64::MAX);
+        Duration::builder().minutes(minutes).build().normalize()
+    }
+
+    /// Returns the component of this [Duration] corresponding to `unit`, e.g. `self.hours`
+    /// for [TimeUnit::Hours].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let duration = "3 days, 4 hours".parse::<Duration>().unwrap();
+    /// assert_eq!(duration.get(TimeUnit::Hours), Number(4));
+    /// assert_eq!(duration.get(TimeUnit::Weeks), Number(0));
+    /// ```
+    pub fn get(&self, unit: TimeUnit) -> Number {
+        match unit {
+            TimeUnit::Minutes => self.minutes,
+            TimeUnit::Hours => self.hours,
+            TimeUnit::Days => self.days,
+            TimeUnit::Weeks => self.weeks,
+            TimeUnit::Months => self.months,
+            TimeUnit::Years => self.years,
+        }
+    }
+
+    /// Sets the component of this [Duration] corresponding to `unit` to `n`, leaving all other
+    /// components untouched.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let mut duration = "3 days, 4 hours".parse::<Duration>().unwrap();
+    /// duration.set(TimeUnit::Hours, Number(6));
+    /// assert_eq!(duration.to_string(), "3 days, 6 hours");
+    /// ```
+    pub fn set(&mut self, unit: TimeUnit, n: Number) {
+        let field = match unit {
+            TimeUnit::Minutes => &mut self.minutes,
+            TimeUnit::Hours => &mut self.hours,
+            TimeUnit::Days => &mut self.days,
+            TimeUnit::Weeks => &mut self.weeks,
+            TimeUnit::Months => &mut self.months,
+            TimeUnit::Years => &mut self.years,
+        };
+        *field = n;
+    }
+
+    /// The total length of this [Duration] in minutes, saturating at [u64::MAX].
+    ///
+    /// Weeks count as 7 days and days as 24 hours. Since months and years do not have a fixed
+    /// length, they are approximated as 30 and 365 days, respectively.
+    ///
+    /// Note that the derived [Ord] impl on [Duration] compares fields lexicographically
+    /// (minutes first), so `2 minutes` sorts after `1 hour`. Compare `total_minutes` instead
+    /// when you want to order durations by their magnitude.
+    pub fn total_minutes(&self) -> u64 {
+        u64::try_from(self.approximate_minutes()).unwrap_or(u64::MAX)
+    }
+
+    /// Renders this [Duration] in PostgreSQL's default (`postgres` style) `interval` output
+    /// format, e.g. `1 year 2 mons 3 days 04:05:00`.
+    ///
+    /// Years and months are kept as-is, since `interval` stores them separately. Weeks are
+    /// folded into days (saturating at [u64::MAX]), as `interval` has no week component, and
+    /// hours are not carried into days. Minutes are carried into hours, since the minute field
+    /// must be below 60 (so `90 minutes` renders as `01:30:00`). A zero [Duration] renders as
+    /// `00:00:00`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let duration = "1 year, 2 months, 3 days, 4 hours, 5 minutes".parse::<Duration>().unwrap();
+    /// assert_eq!(duration.to_postgres_interval(), "1 year 2 mons 3 days 04:05:00");
+    /// ```
+    pub fn to_postgres_interval(&self) -> String {
+        let days = self.weeks.0.saturating_mul(7).saturating_add(self.days.0);
+        let mut parts = Vec::new();
+        for (count, singular, plural) in [
+            (self.years.0, "year", "years"),
+            (self.months.0, "mon", "mons"),
+            (days, "day", "days"),
+        ] {
+            if count > 0 {
+                parts.push(format!(
+                    "{count} {}",
+                    if count == 1 { singular } else { plural }
+                ));
+            }
+        }
+        if self.hours.0 > 0 || self.minutes.0 > 0 || parts.is_empty() {
+            let hours = self.hours.0.saturating_add(self.minutes.0 / 60);
+            parts.push(format!("{hours:02}:{:02}:00", self.minutes.0 % 60));
+        }
+        parts.join(" ")
+    }
+
+    /// Parses a [Duration] from PostgreSQL's default (`postgres` style) `interval` output
+    /// format, i.e. the inverse of [Duration::to_postgres_interval].
+    ///
+    /// Accepts any combination of `N year(s)`, `N mon(s)` and `N day(s)`, optionally followed
+    /// by an `hh:mm(:ss)?` time. Negative components and non-zero seconds cannot be represented
+    /// by a [Duration] and produce an error.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let duration = Duration::parse_postgres_interval("2 mons 10:30:00").unwrap();
+    /// assert_eq!(duration.to_string(), "2 months, 10 hours, 30 minutes");
+    /// ```
+    pub fn parse_postgres_interval(s: &str) -> std::result::Result<Duration, ParseError> {
+        parse_str_with_offset(s, |input| {
+            if input.is_empty() {
+                return Err(input.error("expected interval"));
+            }
+            let mut builder = Duration::builder();
+            while !input.is_empty() {
+                let lit = input.parse::<LitInt>()?;
+                let n = lit.base10_parse::<u64>()?;
+                if input.peek(Token![:]) {
+                    input.parse::<Token![:]>()?;
+                    let minutes = input.parse::<Minute>()?;
+                    if input.peek(Token![:]) {
+                        input.parse::<Token![:]>()?;
+                        let seconds = input.parse::<LitInt>()?;
+                        if seconds.base10_parse::<u64>()? != 0 {
+                            return Err(Error::new(seconds.span(), "seconds are not supported"));
+                        }
+                    }
+                    builder = builder.hours(n).minutes(minutes.0 as u64);
+                    if !input.is_empty() {
+                        return Err(input.error("expected end of interval after time"));
+                    }
+                    break;
+                }
+                let ident = input.parse::<Ident>()?;
+                builder = match ident.to_string().to_lowercase().as_str() {
+                    "year" | "years" => builder.years(n),
+                    "mon" | "mons" => builder.months(n),
+                    "day" | "days" => builder.days(n),
+                    _ => {
+                        return Err(Error::new(
+                            ident.span(),
+                            "expected `years`, `mons`, `days`, or an `hh:mm:ss` time",
+                        ))
+                    }
+                };
+            }
+            Ok(builder.build())
+        })
+    }
+
+    /// The total length of this [Duration] in (fractional) hours, e.g. `1.5` for `90 minutes`.
+    ///
+    /// Since months and years do not have a fixed length, they are approximated as 30 and 365
+    /// days, respectively.
+    pub fn as_fractional_hours(&self) -> f64 {
+        self.approximate_minutes() as f64 / 60.0
+    }
+
+    /// The total length of this [Duration] in (fractional) days, e.g. `1.5` for `36 hours`.
+    ///
+    /// Since months and years do not have a fixed length, they are approximated as 30 and 365
+    /// days, respectively.
+    pub fn as_fractional_days(&self) -> f64 {
+        self.approximate_minutes() as f64 / MINUTES_PER_DAY as f64
+    }
+
+    /// Applies this [Duration] to `date_time`, either forwards or backwards in time.
+    fn shift(&self, date_time: DateTime, forward: bool) -> DateTime {
+        let sign: i128 = if forward { 1 } else { -1 };
+        let DateTime(Date(month, DayOfMonth(day), Year(year)), time) = date_time;
+        let month = year as i128 * 12 + u8::from(month) as i128 - 1
+            + sign * (self.years.0 as i128 * 12 + self.months.0 as i128);
+        let (year, month) = (month.div_euclid(12), month.rem_euclid(12) as u8 + 1);
+        if year < 0 || year > u16::MAX as i128 {
+            // saturate rather than attempting calendar math on an unrepresentable year
+            return DateTime::from_minutes(year.signum() * i128::MAX);
+        }
+        let day = day.min(days_in_month(year as i64, month));
+        let days = days_from_civil(year as i64, month, day);
+        let minutes =
+            ((self.weeks.0 as i128 * 7 + self.days.0 as i128) * 24 + self.hours.0 as i128) * 60
+                + self.minutes.0 as i128;
+        DateTime::from_minutes(
+            days as i128 * MINUTES_PER_DAY + time.minute_of_day() as i128 + sign * minutes,
+        )
+    }
+}
+
+impl From<std::time::Duration> for Duration {
+    /// Decomposes a [std::time::Duration] into weeks, days, hours and minutes. Any leftover
+    /// seconds (and sub-second precision) are truncated. Months and years are always zero,
+    /// since their length is ambiguous.
+    fn from(value: std::time::Duration) -> Self {
+        Duration::builder()
+            .minutes(value.as_secs() / 60)
+            .build()
+            .normalize()
+    }
+}
+
+impl TryFrom<Duration> for std::time::Duration {
+    type Error = CalendarDurationError;
+
+    /// Converts a [Duration] into a [std::time::Duration], failing if it has any months or
+    /// years, since those have no fixed length. See [Duration::to_std_approximate] for a
+    /// lossy alternative. The result saturates at [u64::MAX] seconds.
+    fn try_from(value: Duration) -> std::result::Result<Self, Self::Error> {
+        if value.months.0 > 0 || value.years.0 > 0 {
+            return Err(CalendarDurationError { duration: value });
+        }
+        Ok(value.to_std_approximate())
+    }
+}
+
+impl Duration {
+    /// Converts this [Duration] into a [std::time::Duration], approximating months as 30 days
+    /// and years as 365 days (as in [Duration::total_minutes]), and saturating at [u64::MAX]
+    /// seconds.
+    ///
+    /// Use [TryFrom] instead to reject durations involving months or years.
+    pub fn to_std_approximate(&self) -> std::time::Duration {
+        let seconds = self.approximate_minutes().saturating_mul(60);
+        std::time::Duration::from_secs(u64::try_from(seconds).unwrap_or(u64::MAX))
+    }
+}
+
+/// Returned when converting a [Duration] with months or years into a [std::time::Duration],
+/// since months and years have no fixed length.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct CalendarDurationError {
+    /// The rejected [Duration].
+    pub duration: Duration,
+}
+
+impl Display for CalendarDurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "duration {} includes months or years, which have no fixed length",
+            self.duration
+        )
+    }
+}
+
+impl std::error::Error for CalendarDurationError {}
+
+/// Represents a specific point in time, which could either be an [AbsoluteTime] (corresponding
+/// with a particular [Date] or [DateTime]), or a [RelativeTime] (corresponding with an offset
+/// from some [AbsoluteTime] or "now").
+///
+/// Note that the derived [Ord] impl is structural rather than chronological (for example,
+/// every [PointInTime::Absolute] sorts before every [PointInTime::Relative]). Use
+/// [PointInTime::cmp_resolved] to order points by the instants they resolve to.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PointInTime {
+    /// Based on a specific [Date] or [DateTime] (fixed point) that involves no relative
+    /// indirection, like "3 days after 18/3/2024".
+    Absolute(AbsoluteTime),
+    /// Based on an offset from some known fixed point in time, like "next tuesday".
+    Relative(RelativeTime),
+}
+
+impl Parse for PointInTime {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if Date::peek(input) {
+            Ok(PointInTime::Absolute(input.parse::<AbsoluteTime>()?))
+        } else {
+            Ok(PointInTime::Relative(input.parse::<RelativeTime>()?))
+        }
+    }
+}
+
+impl Display for PointInTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointInTime::Absolute(abs) => write!(f, "{abs}"),
+            PointInTime::Relative(rel) => write!(f, "{rel}"),
+        }
+    }
+}
+
+impl PointInTime {
+    /// An upper bound on the [Display] length of this [PointInTime].
+    fn len_hint(&self) -> usize {
+        match self {
+            PointInTime::Absolute(_) => DATE_TIME_LEN_HINT,
+            PointInTime::Relative(rel) => rel.len_hint(),
+        }
+    }
+
+    /// Whether resolving this [PointInTime] depends on the reference "now".
+    fn requires_now(&self) -> bool {
+        matches!(self, PointInTime::Relative(_))
+    }
+
+    /// Resolves this [PointInTime] to a concrete [DateTime], using `now` as the reference point
+    /// for any relative components.
+    ///
+    /// [AbsoluteTime::Date]s resolve to midnight at the start of that day, and
+    /// [AbsoluteTime::DateTime]s resolve to themselves. See [RelativeTime::resolve] for the
+    /// semantics of relative times.
+    pub fn resolve(&self, now: DateTime) -> DateTime {
+        self.resolve_with(now, ResolveConfig::default())
+    }
+
+    /// Resolves this [PointInTime] like [PointInTime::resolve], but using `config` for any
+    /// relative components (see [RelativeTime::resolve_with]).
+    pub fn resolve_with(&self, now: DateTime, config: ResolveConfig) -> DateTime {
+        match self {
+            PointInTime::Absolute(abs) => abs.date_time(),
+            PointInTime::Relative(rel) => rel.resolve_with(now, config),
+        }
+    }
+
+    /// Converts this [PointInTime] to an [AbsoluteTime], returning it unchanged if it is
+    /// already absolute (so a [AbsoluteTime::Date] stays a date), and otherwise resolving it
+    /// against `now` (see [RelativeTime::resolve]) to an [AbsoluteTime::DateTime].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let point = "37 days from now".parse::<PointInTime>().unwrap();
+    /// assert_eq!(point.to_absolute(now).to_string(), "21/4/2024 at 10:30");
+    /// ```
+    pub fn to_absolute(&self, now: DateTime) -> AbsoluteTime {
+        self.to_absolute_with(now, ResolveConfig::default())
+    }
+
+    /// Converts this [PointInTime] to an [AbsoluteTime] like [PointInTime::to_absolute], but
+    /// using `config` when resolving relative times (see [RelativeTime::resolve_with]).
+    pub fn to_absolute_with(&self, now: DateTime, config: ResolveConfig) -> AbsoluteTime {
+        match self {
+            PointInTime::Absolute(abs) => *abs,
+            PointInTime::Relative(rel) => AbsoluteTime::DateTime(rel.resolve_with(now, config)),
+        }
+    }
+
+    /// Compares this [PointInTime] with `other` chronologically, by resolving both against
+    /// `now` (see [PointInTime::resolve]) and comparing the resulting instants.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let mut points = ["tomorrow", "1/1/2025", "1 day ago"]
+    ///     .map(|s| s.parse::<PointInTime>().unwrap());
+    /// points.sort_by(|a, b| a.cmp_resolved(b, now));
+    /// assert_eq!(points.map(|p| p.to_string()), ["1 day ago", "tomorrow", "1/1/2025"]);
+    /// ```
+    pub fn cmp_resolved(&self, other: &Self, now: DateTime) -> Ordering {
+        self.resolve(now)
+            .minutes()
+            .cmp(&other.resolve(now).minutes())
+    }
+
+    /// Describes when this [PointInTime] occurs relative to `now` in approximate, human-friendly
+    /// terms, such as "in about 3 days", "2 hours ago" or "just now", by resolving it against
+    /// `now` (see [PointInTime::resolve]).
+    ///
+    /// The difference is expressed in a single unit: minutes below an hour, hours below a
+    /// day, days below a week, weeks below 30 days, months below a year, and years beyond
+    /// that, rounded to the nearest whole count. A count that rounds up to the next unit's
+    /// threshold switches to that unit, so 23 hours and 45 minutes reads "in about 1 day". The
+    /// phrasing includes "about" whenever this rounding is lossy, and months and years (which
+    /// vary in length) are always approximate.
+    /// Since resolution is to the minute, "just now" means a difference of under a minute.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// let humanize = |s: &str| s.parse::<PointInTime>().unwrap().humanize(now);
+    /// assert_eq!(humanize("in 3 days and 5 hours"), "in about 3 days");
+    /// assert_eq!(humanize("2 hours ago"), "2 hours ago");
+    /// assert_eq!(humanize("now"), "just now");
+    /// ```
+    pub fn humanize(&self, now: DateTime) -> String {
+        const HOUR: u128 = 60;
+        const DAY: u128 = 24 * HOUR;
+        // the average lengths of a Gregorian month and year
+        const MONTH: u128 = 43_830;
+        const YEAR: u128 = 12 * MONTH;
+        let diff = self.resolve(now).minutes() - now.minutes();
+        let minutes = diff.unsigned_abs();
+        if minutes == 0 {
+            return "just now".to_string();
+        }
+        let unit_for = |minutes: u128| match minutes {
+            m if m < HOUR => (TimeUnit::Minutes, 1),
+            m if m < DAY => (TimeUnit::Hours, HOUR),
+            m if m < 7 * DAY => (TimeUnit::Days, DAY),
+            m if m < 30 * DAY => (TimeUnit::Weeks, 7 * DAY),
+            m if m < 365 * DAY => (TimeUnit::Months, MONTH),
+            _ => (TimeUnit::Years, YEAR),
+        };
+        let rounded = |unit_minutes: u128| ((minutes + unit_minutes / 2) / unit_minutes).max(1);
+        let (_, unit_minutes) = unit_for(minutes);
+        // rounding may reach the next unit, as with "in about 24 hours", so pick the unit again
+        let (unit, unit_minutes) = unit_for(rounded(unit_minutes) * unit_minutes);
+        let count = rounded(unit_minutes);
+        let exact = count * unit_minutes == minutes && unit_minutes < MONTH;
+        let about = if exact { "" } else { "about " };
+        let count = count as u64;
+        let amount = format!("{about}{count} {}", unit.label(count));
+        match diff > 0 {
+            true => format!("in {amount}"),
+            false => format!("{amount} ago"),
+        }
+    }
+}
+
+/// Represents an absolute/fixed point in time, such as a [Date] or [DateTime].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AbsoluteTime {
+    /// A [Date], such as "23/9/2028".
+    Date(Date),
+    /// A [DateTime], such as "28/1/2025 at 5:23 PM" or "1/1/2019 20:15".
+    DateTime(DateTime),
+}
+
+impl Parse for AbsoluteTime {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if Date::peek_iso_date_time(input) {
+            return Ok(AbsoluteTime::DateTime(input.parse()?));
+        }
+        let fork = input.fork();
+        fork.parse::<Date>()?;
+        if (fork.peek(LitInt) && fork.peek2(Token![:]) && fork.peek3(LitInt))
+            || (fork.peek(Ident) && fork.peek2(LitInt) && fork.peek3(Token![:]))
+            || Time::peek_keyword(&fork)
+            || Time::peek_hour_am_pm(&fork)
+        {
+            return Ok(AbsoluteTime::DateTime(input.parse()?));
+        }
+        Ok(AbsoluteTime::Date(input.parse()?))
+    }
+}
+
+impl Display for AbsoluteTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbsoluteTime::Date(date) => write!(f, "{}", date),
+            AbsoluteTime::DateTime(date_time) => write!(f, "{}", date_time),
+        }
+    }
+}
+
+impl AbsoluteTime {
+    /// The [DateTime] this [AbsoluteTime] starts at (midnight in the case of a [Date]).
+    fn date_time(&self) -> DateTime {
+        match self {
+            AbsoluteTime::Date(date) => date.midnight(),
+            AbsoluteTime::DateTime(date_time) => *date_time,
+        }
+    }
+
+    /// Returns the amount of time from this [AbsoluteTime] until `other` as a normalized
+    /// [Duration] (see [Duration::normalize]), or a zero [Duration] if `other` is not after
+    /// `self`. [AbsoluteTime::Date]s are treated as midnight at the start of that day.
+    ///
+    /// The difference is computed exactly using the real calendar, so leap days are
+    /// accounted for. Since months and years vary in length, the result is expressed in weeks,
+    /// days, hours and minutes only.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let start = "1/1/2023".parse::<AbsoluteTime>().unwrap();
+    /// let end = "15/1/2023 at 6:30 PM".parse::<AbsoluteTime>().unwrap();
+    /// assert_eq!(start.duration_until(&end).to_string(), "2 weeks, 18 hours, 30 minutes");
+    /// assert!(end.duration_until(&start).is_zero());
+    /// ```
+    pub fn duration_until(&self, other: &AbsoluteTime) -> Duration {
+        let minutes = other.date_time().minutes() - self.date_time().minutes();
+        Duration::between_minutes(minutes)
+    }
+}
+
+/// A day of the week.
+///
+/// Each [Weekday] has a stable ISO 8601 number (Monday = 1 through Sunday = 7), which is
+/// independent of names and locales (see [Weekday::iso_number] and
+/// [Weekday::from_iso_number]).
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Weekday {
+    /// Monday (1)
+    Monday,
+    /// Tuesday (2)
+    Tuesday,
+    /// Wednesday (3)
+    Wednesday,
+    /// Thursday (4)
+    Thursday,
+    /// Friday (5)
+    Friday,
+    /// Saturday (6)
+    Saturday,
+    /// Sunday (7)
+    Sunday,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+impl Weekday {
+    /// The ISO 8601 number of this [Weekday], from Monday = 1 to Sunday = 7.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(Weekday::Monday.iso_number(), 1);
+    /// assert_eq!(Weekday::Sunday.iso_number(), 7);
+    /// ```
+    pub fn iso_number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// The [Weekday] with the given ISO 8601 number (Monday = 1 to Sunday = 7), or `None` if
+    /// `number` is out of range.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(Weekday::from_iso_number(5), Some(Weekday::Friday));
+    /// assert_eq!(Weekday::from_iso_number(0), None);
+    /// ```
+    pub fn from_iso_number(number: u8) -> Option<Weekday> {
+        WEEKDAYS.get((number as usize).checked_sub(1)?).copied()
+    }
+}
+
+/// Settings used when resolving relative times against a reference "now", as passed to
+/// [PointInTime::resolve_with], [PointInTime::to_absolute_with],
+/// [RelativeTime::resolve_with] and [NamedRelativeTime::resolve_with]. By default, weeks
+/// start on Monday.
+///
+/// ```
+/// use timelang::*;
+/// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+/// let next_week = "next week".parse::<RelativeTime>().unwrap();
+/// let sunday_start = ResolveConfig { week_start: Weekday::Sunday };
+/// assert_eq!(next_week.resolve(now).to_string(), "18/3/2024 at 0:00");
+/// assert_eq!(next_week.resolve_with(now, sunday_start).to_string(), "17/3/2024 at 0:00");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolveConfig {
+    /// The first day of the week, used by `this` / `next` / `last` weeks, `this` weekdays and
+    /// "end of week".
+    pub week_start: Weekday,
+}
+
+impl Default for ResolveConfig {
+    fn default() -> Self {
+        ResolveConfig {
+            week_start: Weekday::Monday,
+        }
+    }
+}
+
+impl ResolveConfig {
+    /// The index of the day `days` since the epoch within its week, where the configured
+    /// [ResolveConfig::week_start] is 0.
+    fn day_of_week(&self, days: i64) -> i64 {
+        (weekday(days) - self.week_start as i64).rem_euclid(7)
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        RelativeTimeUnit::from(*self).fmt(f)
+    }
+}
+
+impl From<Weekday> for RelativeTimeUnit {
+    fn from(value: Weekday) -> Self {
+        match value {
+            Weekday::Monday => RelativeTimeUnit::Monday,
+            Weekday::Tuesday => RelativeTimeUnit::Tuesday,
+            Weekday::Wednesday => RelativeTimeUnit::Wednesday,
+            Weekday::Thursday => RelativeTimeUnit::Thursday,
+            Weekday::Friday => RelativeTimeUnit::Friday,
+            Weekday::Saturday => RelativeTimeUnit::Saturday,
+            Weekday::Sunday => RelativeTimeUnit::Sunday,
+        }
+    }
+}
+
+/// Combined with "next" or "after" to denote specific [RelativeTime]s.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelativeTimeUnit {
     /// Week
     Week,
@@ -692,26 +2897,50 @@ pub enum RelativeTimeUnit {
 impl Parse for RelativeTimeUnit {
     fn parse(input: ParseStream) -> Result<Self> {
         let ident = input.parse::<Ident>()?;
-        match ident.to_string().to_lowercase().as_str() {
-            "week" => Ok(RelativeTimeUnit::Week),
-            "month" => Ok(RelativeTimeUnit::Month),
-            "year" => Ok(RelativeTimeUnit::Year),
-            "monday" => Ok(RelativeTimeUnit::Monday),
-            "tuesday" => Ok(RelativeTimeUnit::Tuesday),
-            "wednesday" => Ok(RelativeTimeUnit::Wednesday),
-            "thursday" => Ok(RelativeTimeUnit::Thursday),
-            "friday" => Ok(RelativeTimeUnit::Friday),
-            "saturday" => Ok(RelativeTimeUnit::Saturday),
-            "sunday" => Ok(RelativeTimeUnit::Sunday),
-            _ => Err(Error::new(
+        find_alias(&RELATIVE_TIME_UNIT_ALIASES, &ident.to_string()).ok_or_else(|| {
+            Error::new(
                 ident.span(),
                 "expected one of `week`, `month`, `year`, `monday`, `tuesday`, `wednesday`, \
                 `thursday`, `friday`, `saturday` or `sunday`",
-            )),
-        }
+            )
+        })
     }
 }
 
+const RELATIVE_TIME_UNIT_ALIASES: [(&str, RelativeTimeUnit); 10] = [
+    ("week", RelativeTimeUnit::Week),
+    ("month", RelativeTimeUnit::Month),
+    ("year", RelativeTimeUnit::Year),
+    ("monday", RelativeTimeUnit::Monday),
+    ("tuesday", RelativeTimeUnit::Tuesday),
+    ("wednesday", RelativeTimeUnit::Wednesday),
+    ("thursday", RelativeTimeUnit::Thursday),
+    ("friday", RelativeTimeUnit::Friday),
+    ("saturday", RelativeTimeUnit::Saturday),
+    ("sunday", RelativeTimeUnit::Sunday),
+];
+
+const RELATIVE_TIME_UNITS: [RelativeTimeUnit; 10] = [
+    RelativeTimeUnit::Week,
+    RelativeTimeUnit::Month,
+    RelativeTimeUnit::Year,
+    RelativeTimeUnit::Monday,
+    RelativeTimeUnit::Tuesday,
+    RelativeTimeUnit::Wednesday,
+    RelativeTimeUnit::Thursday,
+    RelativeTimeUnit::Friday,
+    RelativeTimeUnit::Saturday,
+    RelativeTimeUnit::Sunday,
+];
+
+/// Looks up `name` (case-insensitively) in a table of accepted spellings.
+fn find_alias<T: Copy>(aliases: &[(&str, T)], name: &str) -> Option<T> {
+    aliases
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map(|(_, value)| *value)
+}
+
 impl Display for RelativeTimeUnit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -729,8 +2958,91 @@ impl Display for RelativeTimeUnit {
     }
 }
 
+impl RelativeTimeUnit {
+    /// Every [RelativeTimeUnit]: `week`, `month` and `year`, followed by the weekdays from
+    /// Monday to Sunday.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(RelativeTimeUnit::all().len(), 10);
+    /// ```
+    pub const fn all() -> &'static [RelativeTimeUnit] {
+        &RELATIVE_TIME_UNITS
+    }
+
+    /// Every (lowercase) spelling accepted when parsing a [RelativeTimeUnit], paired with the
+    /// [RelativeTimeUnit] it denotes. Parsing is case-insensitive.
+    pub const fn all_aliases() -> &'static [(&'static str, RelativeTimeUnit)] {
+        &RELATIVE_TIME_UNIT_ALIASES
+    }
+
+    /// The index of this unit within the week (Monday = 0), if it is a weekday.
+    fn weekday_index(&self) -> Option<i64> {
+        match self {
+            RelativeTimeUnit::Monday => Some(0),
+            RelativeTimeUnit::Tuesday => Some(1),
+            RelativeTimeUnit::Wednesday => Some(2),
+            RelativeTimeUnit::Thursday => Some(3),
+            RelativeTimeUnit::Friday => Some(4),
+            RelativeTimeUnit::Saturday => Some(5),
+            RelativeTimeUnit::Sunday => Some(6),
+            RelativeTimeUnit::Week | RelativeTimeUnit::Month | RelativeTimeUnit::Year => None,
+        }
+    }
+
+    /// Resolves the start of the `offset`-th occurrence of this unit relative to `now`, where
+    /// `1` corresponds with "next", `-1` with "last", and `0` with "this".
+    fn resolve(&self, now: DateTime, offset: i64, config: ResolveConfig) -> DateTime {
+        let today = now.0.days();
+        let days = match self {
+            RelativeTimeUnit::Week => today - config.day_of_week(today) + 7 * offset,
+            RelativeTimeUnit::Month => {
+                let Date(month, _, Year(year)) = now.0;
+                let month = year as i64 * 12 + u8::from(month) as i64 - 1 + offset;
+                days_from_civil(month.div_euclid(12), month.rem_euclid(12) as u8 + 1, 1)
+            }
+            RelativeTimeUnit::Year => days_from_civil(now.0 .2 .0 as i64 + offset, 1, 1),
+            weekday_unit if offset == 0 => {
+                let target = weekday_unit.weekday_index().unwrap() - config.week_start as i64;
+                today - config.day_of_week(today) + target.rem_euclid(7)
+            }
+            weekday_unit => {
+                let target = weekday_unit.weekday_index().unwrap();
+                let delta = match offset.signum() {
+                    1 => (target - weekday(today)).rem_euclid(7),
+                    _ => (weekday(today) - target).rem_euclid(7),
+                };
+                let delta = if delta == 0 { 7 } else { delta };
+                today + offset.signum() * delta + 7 * (offset - offset.signum())
+            }
+        };
+        DateTime::from_minutes(days as i128 * MINUTES_PER_DAY)
+    }
+
+    /// Shifts `now` by `offset` occurrences of this unit (or to the `offset`-th occurrence of
+    /// this weekday), as in "this time next week".
+    fn resolve_this_time(&self, now: DateTime, offset: i64, config: ResolveConfig) -> DateTime {
+        let (weeks, months) = match self {
+            RelativeTimeUnit::Week => (1, 0),
+            RelativeTimeUnit::Month => (0, 1),
+            RelativeTimeUnit::Year => (0, 12),
+            _ => return self.resolve(now, offset, config),
+        };
+        Duration {
+            minutes: Number(0),
+            hours: Number(0),
+            days: Number(0),
+            weeks: Number(weeks * offset.unsigned_abs()),
+            months: Number(months * offset.unsigned_abs()),
+            years: Number(0),
+        }
+        .shift(now, offset > 0)
+    }
+}
+
 /// Corresponds with a named relative time, such as "now", "today", "tomorrow", etc.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NamedRelativeTime {
     /// Now
     Now,
@@ -744,54 +3056,106 @@ pub enum NamedRelativeTime {
     DayAfterTomorrow,
     /// The day before yesterday
     DayBeforeYesterday,
+    /// Now, as observed in a particular [UtcOffset], e.g. "now UTC" or "now +09:00"
+    NowIn(UtcOffset),
+    /// Noon today, written as "midday", "mid day" or "mid-day"
+    Midday,
+    /// The last minute of today, written as "end of day" or "end-of-day"
+    EndOfDay,
+    /// The last minute of the current week (weeks start on Monday), written as "end of week"
+    EndOfWeek,
+    /// The last minute of the current month, written as "end of month"
+    EndOfMonth,
+    /// The last minute of the current year, written as "end of year"
+    EndOfYear,
 }
 
 impl Parse for NamedRelativeTime {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut ident1 = input.parse::<Ident>()?;
         if let Some(variant) = match ident1.to_string().to_lowercase().as_str() {
+            "now" if UtcOffset::peek(input) => Some(NamedRelativeTime::NowIn(input.parse()?)),
             "now" => Some(NamedRelativeTime::Now),
             "today" => Some(NamedRelativeTime::Today),
             "tomorrow" => Some(NamedRelativeTime::Tomorrow),
             "yesterday" => Some(NamedRelativeTime::Yesterday),
+            "midday" => Some(NamedRelativeTime::Midday),
             _ => None,
         } {
             // single-ident variants
             return Ok(variant);
         }
-        if ident1 == "the" && input.peek(Ident) {
+        if ident1.to_string().to_lowercase() == "the" && input.peek(Ident) {
             // optional "the"
             ident1 = input.parse::<Ident>()?;
         }
+        // words may be joined by hyphens, as in `mid-day` or `day-after-tomorrow`
+        let skip_hyphen = |input: ParseStream| -> Result<()> {
+            if input.peek(Token![-]) && input.peek2(Ident) {
+                input.parse::<Token![-]>()?;
+            }
+            Ok(())
+        };
+        skip_hyphen(input)?;
         let ident2 = input.parse::<Ident>()?;
-        let ident3 = input.parse::<Ident>()?;
         let ident1_str = ident1.to_string().to_lowercase();
         let ident2_str = ident2.to_string().to_lowercase();
+        if ident1_str == "mid" {
+            if ident2_str != "day" {
+                return Err(Error::new(ident2.span(), "expected `day`"));
+            }
+            return Ok(NamedRelativeTime::Midday);
+        }
+        // the third word is only consumed once it is known to be valid, so that any tokens
+        // following a complete phrase are left for the caller, and so that errors about it are
+        // reported at (rather than after) it
+        skip_hyphen(input)?;
+        let mut ident3 = input.fork().parse::<Ident>()?;
+        if ident1_str == "end" && ident3.to_string().to_lowercase() == "the" {
+            // optional "the", as in `end of the week`
+            input.parse::<Ident>()?;
+            skip_hyphen(input)?;
+            ident3 = input.fork().parse::<Ident>()?;
+        }
         let ident3_str = ident3.to_string().to_lowercase();
-        match (
+        let variant = match (
             ident1_str.as_str(),
             ident2_str.as_str(),
             ident3_str.as_str(),
         ) {
             ("day", "after", "tomorrow") => Ok(NamedRelativeTime::DayAfterTomorrow),
             ("day", "before", "yesterday") => Ok(NamedRelativeTime::DayBeforeYesterday),
+            ("end", "of", "day") => Ok(NamedRelativeTime::EndOfDay),
+            ("end", "of", "week") => Ok(NamedRelativeTime::EndOfWeek),
+            ("end", "of", "month") => Ok(NamedRelativeTime::EndOfMonth),
+            ("end", "of", "year") => Ok(NamedRelativeTime::EndOfYear),
+            ("end", "of", _) => Err(Error::new(
+                ident3.span(),
+                "expected one of `day`, `week`, `month`, `year`",
+            )),
+            ("end", _, _) => Err(Error::new(ident2.span(), "expected `of`")),
             _ => {
                 if ident1_str != "day" {
                     return Err(Error::new(
                         ident1.span(),
-                        "expected one of `day`, `now`, `today`, `tomorrow`, `yesterday`, `the`",
+                        "expected one of `day`, `end`, `mid`, `midday`, `now`, `today`, `tomorrow`, `yesterday`, `the`",
                     ));
                 }
-                if ident2_str != "before" && ident2_str != "after" {
-                    return Err(Error::new(ident2.span(), "expected `before` or `after`"));
-                }
-                if ident3_str == "tomorrow" {
-                    Err(Error::new(ident3.span(), "expected `yesterday`"))
-                } else {
-                    Err(Error::new(ident3.span(), "expected `tomorrow`"))
+                match ident2_str.as_str() {
+                    "after" => Err(Error::new(
+                        ident3.span(),
+                        "expected `tomorrow` after `day after`",
+                    )),
+                    "before" => Err(Error::new(
+                        ident3.span(),
+                        "expected `yesterday` after `day before`",
+                    )),
+                    _ => Err(Error::new(ident2.span(), "expected `before` or `after`")),
                 }
             }
-        }
+        }?;
+        input.parse::<Ident>()?;
+        Ok(variant)
     }
 }
 
@@ -804,16 +3168,90 @@ impl Display for NamedRelativeTime {
             NamedRelativeTime::Yesterday => f.write_str("yesterday"),
             NamedRelativeTime::DayAfterTomorrow => f.write_str("the day after tomorrow"),
             NamedRelativeTime::DayBeforeYesterday => f.write_str("the day before yesterday"),
+            NamedRelativeTime::NowIn(offset) => write!(f, "now {offset}"),
+            NamedRelativeTime::Midday => f.write_str("mid day"),
+            NamedRelativeTime::EndOfDay => f.write_str("end of day"),
+            NamedRelativeTime::EndOfWeek => f.write_str("end of week"),
+            NamedRelativeTime::EndOfMonth => f.write_str("end of month"),
+            NamedRelativeTime::EndOfYear => f.write_str("end of year"),
+        }
+    }
+}
+
+impl NamedRelativeTime {
+    /// Resolves this [NamedRelativeTime] to a concrete [DateTime] using `now` as the reference
+    /// point.
+    ///
+    /// [NamedRelativeTime::Now] resolves to `now` itself, [NamedRelativeTime::Midday] to noon
+    /// today and [NamedRelativeTime::EndOfDay] to 23:59 today, while the remaining day-based
+    /// variants resolve to midnight at the start of the corresponding day.
+    /// [NamedRelativeTime::EndOfWeek], [NamedRelativeTime::EndOfMonth] and
+    /// [NamedRelativeTime::EndOfYear] resolve to 23:59 on the last day of the current week
+    /// (the coming Sunday, or today if it is Sunday, unless configured otherwise via
+    /// [NamedRelativeTime::resolve_with]), the last day of the current month and 31/12 of the
+    /// current year, respectively.
+    ///
+    /// [NamedRelativeTime::NowIn] assumes that `now` is expressed in UTC, and resolves to the
+    /// wall-clock time observed at the specified [UtcOffset] at that instant.
+    pub fn resolve(&self, now: DateTime) -> DateTime {
+        self.resolve_with(now, ResolveConfig::default())
+    }
+
+    /// Resolves this [NamedRelativeTime] like [NamedRelativeTime::resolve], but using
+    /// `config` (e.g. to pick the day "end of week" refers to).
+    pub fn resolve_with(&self, now: DateTime, config: ResolveConfig) -> DateTime {
+        let offset = match self {
+            NamedRelativeTime::Now => return now,
+            NamedRelativeTime::NowIn(UtcOffset(offset)) => {
+                return DateTime::from_minutes(now.minutes() + *offset as i128)
+            }
+            NamedRelativeTime::Today => 0,
+            NamedRelativeTime::Tomorrow => 1,
+            NamedRelativeTime::Yesterday => -1,
+            NamedRelativeTime::DayAfterTomorrow => 2,
+            NamedRelativeTime::DayBeforeYesterday => -2,
+            NamedRelativeTime::Midday => {
+                return DateTime::from_minutes(now.0.days() as i128 * MINUTES_PER_DAY + 12 * 60)
+            }
+            NamedRelativeTime::EndOfDay => 0,
+            NamedRelativeTime::EndOfWeek => 6 - config.day_of_week(now.0.days()),
+            NamedRelativeTime::EndOfMonth => {
+                let Date(month, DayOfMonth(day), Year(year)) = now.0;
+                days_in_month(year as i64, month.into()) as i64 - day as i64
+            }
+            NamedRelativeTime::EndOfYear => {
+                let Date(_, _, Year(year)) = now.0;
+                days_from_civil(year as i64, 12, 31) - now.0.days()
+            }
+        };
+        if matches!(
+            self,
+            NamedRelativeTime::EndOfDay
+                | NamedRelativeTime::EndOfWeek
+                | NamedRelativeTime::EndOfMonth
+                | NamedRelativeTime::EndOfYear
+        ) {
+            // the last minute of the day
+            return DateTime::from_minutes(
+                (now.0.days() + offset + 1) as i128 * MINUTES_PER_DAY - 1,
+            );
         }
+        DateTime::from_minutes((now.0.days() + offset) as i128 * MINUTES_PER_DAY)
     }
 }
 
 /// Represents a specific point in time offset by some known duration or period, such as
 /// "tomorrow", "now", "next tuesday", "3 days after 2/5/2028 at 7:11 PM" etc..
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelativeTime {
     /// e.g. "3 hours before 18/9/2024 at 4:32 PM", "7 days and 3 hours after tomorrow", "5
     /// days ago", "9 years from now".
+    ///
+    /// "in 3 days" is also accepted as shorthand for "3 days from now", and is displayed in
+    /// the latter form. Likewise, "+3 days" and "-3 days" are accepted as shorthand for "3
+    /// days from now" and "3 days ago", which is handy for range endpoints such as "from now to
+    /// +3 days".
     Directional {
         /// The [Duration] (how long).
         duration: Duration,
@@ -826,14 +3264,104 @@ pub enum RelativeTime {
     Next(RelativeTimeUnit),
     /// e.g. "last month", "last tuesday", "last year".
     Last(RelativeTimeUnit),
+    /// e.g. "this time tomorrow", "this time the day after tomorrow".
+    ThisTimeNamed(NamedRelativeTime),
+    /// e.g. "this time next week", "this time next friday".
+    ThisTimeNext(RelativeTimeUnit),
+    /// e.g. "this time last year", "this time last monday".
+    ThisTimeLast(RelativeTimeUnit),
+    /// A [NamedRelativeTime] at a particular [Time], e.g. "tomorrow at noon", "noon tomorrow",
+    /// "yesterday at 17:30". Displayed in the `[named] at [time]` form.
+    ///
+    /// The time may carry an exactness qualifier, as in "tomorrow at 3 PM sharp" or "at exactly
+    /// 15:00 today" (see [QualifiedTime]), which is preserved but does not affect resolution.
+    NamedAt(NamedRelativeTime, QualifiedTime),
+    /// e.g. "this friday", "this week", "this month".
+    This(RelativeTimeUnit),
+    /// A bare weekday, e.g. "friday", "sunday", meaning the next occurrence of that weekday
+    /// (and therefore resolving exactly like "next friday").
+    Weekday(Weekday),
+    /// e.g. "tuesday after next", "the friday after next", meaning the occurrence of that
+    /// weekday after the next one.
+    WeekdayAfterNext(Weekday),
 }
 
 impl Parse for RelativeTime {
     fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![+]) || input.peek(Token![-]) {
+            // +[duration] / -[duration], i.e. [duration] from now / [duration] ago
+            let dir = match input.parse::<Token![+]>() {
+                Ok(_) => TimeDirection::FromNow,
+                Err(_) => {
+                    input.parse::<Token![-]>()?;
+                    TimeDirection::Ago
+                }
+            };
+            return Ok(RelativeTime::Directional {
+                duration: input.parse()?,
+                dir,
+            });
+        }
+        if input.peek(Token![in]) {
+            // in [duration] ('s time)?, i.e. [duration] from now
+            input.parse::<Token![in]>()?;
+            let duration = input.parse()?;
+            let possessive = input.peek(Lifetime);
+            if possessive {
+                let lifetime = input.parse::<Lifetime>()?;
+                if lifetime.ident.to_string().to_lowercase() != "s" {
+                    return Err(Error::new(lifetime.span(), "expected `'s time`"));
+                }
+            }
+            let time = input
+                .fork()
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident.to_string().to_lowercase() == "time");
+            if possessive || time {
+                let ident = input.parse::<Ident>()?;
+                if ident.to_string().to_lowercase() != "time" {
+                    return Err(Error::new(ident.span(), "expected `time`"));
+                }
+            }
+            return Ok(RelativeTime::Directional {
+                duration,
+                dir: TimeDirection::FromNow,
+            });
+        }
         let fork = input.fork();
         if fork.peek(Ident) {
             let ident1 = fork.parse::<Ident>().unwrap().to_string().to_lowercase();
+            if ident1 == "the" && RelativeTime::parse_weekday(&fork).is_ok() {
+                // the [weekday] after next
+                input.parse::<Ident>()?;
+                let weekday = RelativeTime::parse_weekday(input)?;
+                RelativeTime::parse_after_next(input)?;
+                return Ok(RelativeTime::WeekdayAfterNext(weekday));
+            }
             match ident1.as_str() {
+                "this" => {
+                    // this [unit] / this time [named] / this time next / last [unit]
+                    input.parse::<Ident>()?;
+                    if input.fork().parse::<RelativeTimeUnit>().is_ok() {
+                        return Ok(RelativeTime::This(input.parse()?));
+                    }
+                    let ident2 = input.parse::<Ident>()?;
+                    if ident2.to_string().to_lowercase() != "time" {
+                        return Err(Error::new(ident2.span(), "expected `time`"));
+                    }
+                    let ident3 = input.fork().parse::<Ident>()?.to_string().to_lowercase();
+                    return match ident3.as_str() {
+                        "next" => {
+                            input.parse::<Ident>()?;
+                            Ok(RelativeTime::ThisTimeNext(input.parse()?))
+                        }
+                        "last" => {
+                            input.parse::<Ident>()?;
+                            Ok(RelativeTime::ThisTimeLast(input.parse()?))
+                        }
+                        _ => Ok(RelativeTime::ThisTimeNamed(input.parse()?)),
+                    };
+                }
                 "next" | "last" => {
                     // next / last [unit]
                     input.parse::<Ident>()?;
@@ -844,40 +3372,596 @@ impl Parse for RelativeTime {
                         return Ok(RelativeTime::Last(unit));
                     }
                 }
-                "day" | "now" | "today" | "tomorrow" | "yesterday" | "the" => {
-                    return Ok(RelativeTime::Named(input.parse::<NamedRelativeTime>()?))
+                "day" | "now" | "today" | "tomorrow" | "yesterday" | "the" | "mid" | "midday"
+                | "end" => {
+                    let named = input.parse::<NamedRelativeTime>()?;
+                    let fork = input.fork();
+                    if fork
+                        .parse::<Ident>()
+                        .is_ok_and(|ident| ident.to_string().to_lowercase() == "at")
+                    {
+                        // [named] at [time]
+                        input.parse::<Ident>()?;
+                        return Ok(RelativeTime::NamedAt(named, input.parse()?));
+                    }
+                    return Ok(RelativeTime::Named(named));
+                }
+                "monday" | "tuesday" | "wednesday" | "thursday" | "friday" | "saturday"
+                | "sunday" => {
+                    // [weekday] (after next)?
+                    let weekday = RelativeTime::parse_weekday(input)?;
+                    let fork = input.fork();
+                    if RelativeTime::parse_after_next(&fork).is_ok() {
+                        RelativeTime::parse_after_next(input)?;
+                        return Ok(RelativeTime::WeekdayAfterNext(weekday));
+                    }
+                    return Ok(RelativeTime::Weekday(weekday));
+                }
+                "at" | "noon" | "midnight" | "exactly" => {
+                    // (at)? [time] [named]
+                    if ident1 == "at" {
+                        input.parse::<Ident>()?;
+                    }
+                    let time = input.parse::<QualifiedTime>()?;
+                    return Ok(RelativeTime::NamedAt(input.parse()?, time));
                 }
                 _ => (),
             }
         }
-        let duration = input.parse::<Duration>()?;
-        let dir = input.parse::<TimeDirection>()?;
-        Ok(RelativeTime::Directional { duration, dir })
+        let duration = input.parse::<Duration>()?;
+        let dir = input.parse::<TimeDirection>()?;
+        Ok(RelativeTime::Directional { duration, dir })
+    }
+}
+
+impl RelativeTime {
+    /// Parses a weekday name, e.g. `friday`.
+    fn parse_weekday(input: ParseStream) -> Result<Weekday> {
+        let span = input.span();
+        let unit = input.parse::<RelativeTimeUnit>()?;
+        match unit.weekday_index() {
+            Some(index) => Ok(WEEKDAYS[index as usize]),
+            None => Err(Error::new(span, "expected weekday")),
+        }
+    }
+
+    /// Parses the trailing `after next` of e.g. "tuesday after next".
+    fn parse_after_next(input: ParseStream) -> Result<()> {
+        for expected in ["after", "next"] {
+            let ident = input.parse::<Ident>()?;
+            if ident.to_string().to_lowercase() != expected {
+                return Err(Error::new(ident.span(), format!("expected `{expected}`")));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for RelativeTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RelativeTime::Directional { duration, dir } => write!(f, "{duration} {dir}"),
+            RelativeTime::Next(unit) => write!(f, "next {unit}"),
+            RelativeTime::Last(unit) => write!(f, "last {unit}"),
+            RelativeTime::This(unit) => write!(f, "this {unit}"),
+            RelativeTime::Weekday(weekday) => write!(f, "{weekday}"),
+            RelativeTime::WeekdayAfterNext(weekday) => write!(f, "{weekday} after next"),
+            RelativeTime::Named(named) => write!(f, "{named}"),
+            RelativeTime::ThisTimeNamed(named) => write!(f, "this time {named}"),
+            RelativeTime::ThisTimeNext(unit) => write!(f, "this time next {unit}"),
+            RelativeTime::ThisTimeLast(unit) => write!(f, "this time last {unit}"),
+            RelativeTime::NamedAt(named, time) => write!(f, "{named} at {time}"),
+        }
+    }
+}
+
+impl RelativeTime {
+    /// An upper bound on the [Display] length of this [RelativeTime].
+    fn len_hint(&self) -> usize {
+        let unit = "next ".len() + UNIT_LEN_HINT;
+        match self {
+            RelativeTime::Directional { duration, .. } => {
+                duration.len_hint() + 1 + DIRECTION_LEN_HINT
+            }
+            RelativeTime::Next(_) | RelativeTime::Last(_) | RelativeTime::This(_) => unit,
+            RelativeTime::Weekday(_) => UNIT_LEN_HINT,
+            RelativeTime::WeekdayAfterNext(_) => UNIT_LEN_HINT + " after next".len(),
+            RelativeTime::Named(_) => NAMED_LEN_HINT,
+            RelativeTime::ThisTimeNamed(_)
+            | RelativeTime::ThisTimeNext(_)
+            | RelativeTime::ThisTimeLast(_) => "this time ".len() + NAMED_LEN_HINT.max(unit),
+            RelativeTime::NamedAt(..) => {
+                NAMED_LEN_HINT + " at ".len() + TIME_LEN_HINT + " sharp".len()
+            }
+        }
+    }
+
+    /// Resolves this [RelativeTime] to a concrete [DateTime], using `now` as the reference
+    /// point.
+    ///
+    /// - [NamedRelativeTime]s resolve as described in [NamedRelativeTime::resolve].
+    /// - `next` / `last` weekdays resolve to midnight on the closest matching weekday strictly
+    ///   after / before the current day. Bare weekdays ([RelativeTime::Weekday]) resolve like
+    ///   `next` weekdays, i.e. "friday" on a Friday means the following Friday, while
+    ///   "friday after next" skips that occurrence and lands on the one a week later.
+    /// - `next` / `last` weeks, months, and years resolve to midnight at the start of the
+    ///   following / preceding week (weeks start on Monday, see [RelativeTime::resolve_with]),
+    ///   month, or year.
+    /// - `this` weekdays resolve to midnight on that weekday within the current week (which may
+    ///   be in the past), while `this week` / `month` / `year` resolve to midnight at the start of
+    ///   the current week, month, or year.
+    /// - `this time` variants keep the wall-clock time of `now`, but move to the date of the
+    ///   corresponding named day or weekday. `this time next week` / `month` / `year` (and their
+    ///   `last` counterparts) shift `now` by exactly one week, month, or year.
+    /// - [RelativeTime::NamedAt] resolves to the specified [Time] on the date of the resolved
+    ///   [NamedRelativeTime].
+    /// - [RelativeTime::Directional] adds (or, for `ago` / `before`, subtracts) the [Duration] to
+    ///   the resolved anchor, where [AbsoluteTime::Date] anchors start at midnight. Years and
+    ///   months are applied first as calendar months (clamping the day to the end of the
+    ///   resulting month where necessary), followed by the remaining units.
+    ///
+    /// Results that would fall outside of the range representable by [Year] saturate to the
+    /// first or last representable minute.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// assert_eq!(
+    ///     "3 days ago".parse::<RelativeTime>().unwrap().resolve(now),
+    ///     "12/3/2024 at 10:30".parse::<DateTime>().unwrap()
+    /// );
+    /// ```
+    pub fn resolve(&self, now: DateTime) -> DateTime {
+        self.resolve_with(now, ResolveConfig::default())
+    }
+
+    /// Resolves this [RelativeTime] like [RelativeTime::resolve], but using `config` (e.g. to
+    /// change the day weeks start on).
+    pub fn resolve_with(&self, now: DateTime, config: ResolveConfig) -> DateTime {
+        match self {
+            RelativeTime::Directional { duration, dir } => match dir {
+                TimeDirection::AfterAbsolute(abs) => duration.shift(abs.date_time(), true),
+                TimeDirection::BeforeAbsolute(abs) => duration.shift(abs.date_time(), false),
+                TimeDirection::AfterNamed(named) => {
+                    duration.shift(named.resolve_with(now, config), true)
+                }
+                TimeDirection::BeforeNamed(named) => {
+                    duration.shift(named.resolve_with(now, config), false)
+                }
+                TimeDirection::BeforeNext(unit) => {
+                    duration.shift(unit.resolve(now, 1, config), false)
+                }
+                TimeDirection::BeforeLast(unit) => {
+                    duration.shift(unit.resolve(now, -1, config), false)
+                }
+                TimeDirection::AfterNext(unit) => {
+                    duration.shift(unit.resolve(now, 1, config), true)
+                }
+                TimeDirection::AfterLast(unit) => {
+                    duration.shift(unit.resolve(now, -1, config), true)
+                }
+                TimeDirection::Ago => duration.shift(now, false),
+                TimeDirection::FromNow => duration.shift(now, true),
+            },
+            RelativeTime::Named(named) => named.resolve_with(now, config),
+            RelativeTime::Next(unit) => unit.resolve(now, 1, config),
+            RelativeTime::Last(unit) => unit.resolve(now, -1, config),
+            RelativeTime::This(unit) => unit.resolve(now, 0, config),
+            RelativeTime::Weekday(weekday) => {
+                RelativeTimeUnit::from(*weekday).resolve(now, 1, config)
+            }
+            RelativeTime::WeekdayAfterNext(weekday) => {
+                RelativeTimeUnit::from(*weekday).resolve(now, 2, config)
+            }
+            RelativeTime::ThisTimeNamed(named) => {
+                DateTime(named.resolve_with(now, config).0, now.1)
+            }
+            RelativeTime::ThisTimeNext(unit) => {
+                DateTime(unit.resolve_this_time(now, 1, config).0, now.1)
+            }
+            RelativeTime::ThisTimeLast(unit) => {
+                DateTime(unit.resolve_this_time(now, -1, config).0, now.1)
+            }
+            RelativeTime::NamedAt(named, time) => {
+                DateTime(named.resolve_with(now, config).0, time.0)
+            }
+        }
+    }
+
+    /// Resolves this [RelativeTime] to the [TimeRange] it covers, using `now` as the reference
+    /// point. Both endpoints of the returned range are inclusive [AbsoluteTime::DateTime]s.
+    ///
+    /// The following variants are day-granular (or coarser), and cover the whole period
+    /// starting at [RelativeTime::resolve]:
+    /// - the day-based [NamedRelativeTime]s (`today`, `tomorrow`, `yesterday`, `the day after
+    ///   tomorrow`, `the day before yesterday`) cover the whole day, from 0:00 to 23:59.
+    /// - bare, `next` / `last` / `this` weekdays cover the whole day, and `next` / `last` / `this`
+    ///   weeks, months, and years cover the whole week, month, or year.
+    ///
+    /// All other variants (such as `now`, `this time tomorrow`, or `3 hours from now`) refer to
+    /// a single instant and produce a zero-width range.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    /// assert_eq!(
+    ///     "tomorrow".parse::<RelativeTime>().unwrap().resolve_range(now).to_string(),
+    ///     "from 16/3/2024 at 0:00 to 16/3/2024 at 23:59"
+    /// );
+    /// ```
+    pub fn resolve_range(&self, now: DateTime) -> TimeRange {
+        use NamedRelativeTime::*;
+        let start = self.resolve(now);
+        let span = |days, months| Duration::builder().days(days).months(months).build();
+        let span = match self {
+            RelativeTime::Named(
+                Today | Tomorrow | Yesterday | DayAfterTomorrow | DayBeforeYesterday,
+            )
+            | RelativeTime::Weekday(_)
+            | RelativeTime::WeekdayAfterNext(_) => Some(span(1, 0)),
+            RelativeTime::Next(unit) | RelativeTime::Last(unit) | RelativeTime::This(unit) => {
+                Some(match unit {
+                    RelativeTimeUnit::Week => span(7, 0),
+                    RelativeTimeUnit::Month => span(0, 1),
+                    RelativeTimeUnit::Year => span(0, 12),
+                    _ => span(1, 0),
+                })
+            }
+            _ => None,
+        };
+        let end = match span {
+            Some(span) => DateTime::from_minutes((start + span).minutes() - 1),
+            None => start,
+        };
+        TimeRange(
+            PointInTime::Absolute(AbsoluteTime::DateTime(start)),
+            PointInTime::Absolute(AbsoluteTime::DateTime(end)),
+        )
+    }
+}
+
+/// A sequence of successive [RelativeTime]s joined by `and then`, such as "2 hours from now
+/// and then 30 minutes later". After the first step, `[duration] later` may be used as
+/// shorthand for `[duration] from now`, and is how such steps are displayed.
+///
+/// Each step is resolved using the instant resolved for the previous step as its "now", so
+/// offsets accumulate (see [Sequence::resolve]).
+///
+/// ```
+/// use timelang::*;
+/// let now = "15/3/2024 at 10:00".parse::<DateTime>().unwrap();
+/// let sequence = "2 hours from now and then 30 minutes later"
+///     .parse::<Sequence>()
+///     .unwrap();
+/// assert_eq!(
+///     sequence.resolve(now).last(),
+///     Some(&"15/3/2024 at 12:30".parse::<DateTime>().unwrap())
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sequence(pub Vec<RelativeTime>);
+
+impl Parse for Sequence {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut steps = vec![input.parse::<RelativeTime>()?];
+        while input.peek(Ident) && input.peek2(Ident) {
+            let fork = input.fork();
+            let and = fork.parse::<Ident>()?.to_string().to_lowercase();
+            let then = fork.parse::<Ident>()?.to_string().to_lowercase();
+            if and != "and" || then != "then" {
+                break;
+            }
+            input.parse::<Ident>()?;
+            input.parse::<Ident>()?;
+            // [duration] later
+            let fork = input.fork();
+            if fork.parse::<Duration>().is_ok()
+                && fork
+                    .parse::<Ident>()
+                    .is_ok_and(|ident| ident.to_string().to_lowercase() == "later")
+            {
+                let duration = input.parse::<Duration>()?;
+                input.parse::<Ident>()?;
+                steps.push(RelativeTime::Directional {
+                    duration,
+                    dir: TimeDirection::FromNow,
+                });
+                continue;
+            }
+            steps.push(input.parse()?);
+        }
+        Ok(Sequence(steps))
+    }
+}
+
+impl Display for Sequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, step) in self.0.iter().enumerate() {
+            match step {
+                _ if i == 0 => write!(f, "{step}")?,
+                RelativeTime::Directional {
+                    duration,
+                    dir: TimeDirection::FromNow,
+                } => write!(f, " and then {duration} later")?,
+                _ => write!(f, " and then {step}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Sequence {
+    /// Resolves each step of this [Sequence] in turn, returning the resolved instant for every
+    /// step. The first step is resolved against `now`, and each subsequent step is resolved
+    /// against the instant resolved for the step before it.
+    pub fn resolve(&self, now: DateTime) -> Vec<DateTime> {
+        let mut current = now;
+        self.0
+            .iter()
+            .map(|step| {
+                current = step.resolve(current);
+                current
+            })
+            .collect()
+    }
+}
+
+/// A repeating interval, such as "every 2 weeks" or "every day".
+///
+/// The interval may be any non-zero [Duration] (e.g. "every 1 hour, 30 minutes"). When no
+/// number is given, as in "every day" or "every fortnight", a single unit is assumed.
+/// Intervals consisting of exactly one of a single unit are displayed without the number.
+///
+/// A recurrence can optionally be anchored to a `starting` [PointInTime], as in "every 2 weeks
+/// starting 1/1/2024", in which case occurrences fall on that point plus whole multiples of
+/// the interval (see [Recurrence::next_after]).
+///
+/// ```
+/// use timelang::*;
+/// let recurrence = "every 2 weeks".parse::<Recurrence>().unwrap();
+/// assert_eq!(recurrence.interval, Duration::builder().weeks(2).build());
+/// assert_eq!(recurrence.start, None);
+/// assert_eq!("every 1 day".parse::<Recurrence>().unwrap().to_string(), "every day");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recurrence {
+    /// How much time passes between successive occurrences.
+    pub interval: Duration,
+    /// The point occurrences are anchored to, if any.
+    pub start: Option<PointInTime>,
+}
+
+impl Parse for Recurrence {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let every = input.parse::<Ident>()?;
+        if every.to_string().to_lowercase() != "every" {
+            return Err(Error::new(every.span(), "expected `every`"));
+        }
+        let span = input.span();
+        let interval = if Duration::peek_quantity(input) {
+            input.parse::<Duration>()?
+        } else {
+            let (count, unit) = Duration::parse_unit(input)?;
+            let mut interval = Duration::builder().build();
+            interval.set(unit, Number(count));
+            interval
+        };
+        if interval.is_zero() {
+            return Err(Error::new(span, "recurrence interval must not be zero"));
+        }
+        let mut start = None;
+        if input.peek(Ident) {
+            let ident = input.parse::<Ident>()?;
+            if ident.to_string().to_lowercase() != "starting" {
+                return Err(Error::new(ident.span(), "expected `starting`"));
+            }
+            start = Some(input.parse::<PointInTime>()?);
+        }
+        Ok(Recurrence { interval, start })
+    }
+}
+
+impl Recurrence {
+    /// Returns the first occurrence of this [Recurrence] strictly after `now`.
+    ///
+    /// Occurrences of an anchored recurrence fall on its `start` (resolved against `now`, see
+    /// [PointInTime::resolve]) plus whole multiples of the interval, so if the start is still
+    /// in the future it is itself the next occurrence. Calendar units are applied as when
+    /// adding a [Duration] to a [DateTime], always relative to the start rather than to the
+    /// previous occurrence, so "every month starting 31/1/2024" yields 29/2/2024 followed by
+    /// 31/3/2024. Without a `start`, occurrences are anchored to `now` and the next one is
+    /// simply one interval later.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let now = "10/1/2024 at 12:00".parse::<DateTime>().unwrap();
+    /// let recurrence = "every 2 weeks starting 1/1/2024".parse::<Recurrence>().unwrap();
+    /// assert_eq!(recurrence.next_after(now).to_string(), "15/1/2024 at 0:00");
+    /// let recurrence = "every 2 weeks".parse::<Recurrence>().unwrap();
+    /// assert_eq!(recurrence.next_after(now).to_string(), "24/1/2024 at 12:00");
+    /// ```
+    pub fn next_after(&self, now: DateTime) -> DateTime {
+        let Some(start) = self.start else {
+            return self.interval.shift(now, true);
+        };
+        let anchor = start.resolve(now);
+        let period = self.interval.approximate_minutes();
+        if anchor.minutes() > now.minutes() || period == 0 {
+            return anchor;
+        }
+        // compare via `minutes`, as the derived ordering on `DateTime` is not chronological
+        let occurrence = |k: u64| (self.interval * k).shift(anchor, true);
+        let is_after_now = |date_time: DateTime| date_time.minutes() > now.minutes();
+        // start from an estimate, then correct for calendar units varying in length
+        let estimate = (now.minutes() - anchor.minutes()) as u128 / period;
+        let mut k = u64::try_from(estimate).unwrap_or(u64::MAX).max(1);
+        while k > 1 && is_after_now(occurrence(k - 1)) {
+            k -= 1;
+        }
+        let max = DateTime::from_minutes(i128::MAX);
+        loop {
+            let next = occurrence(k);
+            if is_after_now(next) || next == max {
+                return next;
+            }
+            k += 1;
+        }
+    }
+}
+
+impl Display for Recurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Duration {
+            minutes,
+            hours,
+            days,
+            weeks,
+            months,
+            years,
+        } = self.interval;
+        let units = [
+            (years, TimeUnit::Years),
+            (months, TimeUnit::Months),
+            (weeks, TimeUnit::Weeks),
+            (days, TimeUnit::Days),
+            (hours, TimeUnit::Hours),
+            (minutes, TimeUnit::Minutes),
+        ];
+        let mut nonzero = units.iter().filter(|(count, _)| *count != 0);
+        match (nonzero.next(), nonzero.next()) {
+            (Some((Number(1), unit)), None) => write!(f, "every {}", unit.label(1))?,
+            _ => write!(f, "every {}", self.interval)?,
+        }
+        if let Some(start) = self.start {
+            write!(f, " starting {start}")?;
+        }
+        Ok(())
     }
 }
 
-impl Display for RelativeTime {
+/// Returned by [Date::new] when the given day, month and year do not form a valid [Date].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum DateError {
+    /// The month was not between 1 and 12 (inclusive).
+    InvalidMonth(u8),
+    /// The day was not between 1 and 31 (inclusive).
+    InvalidDay(u8),
+    /// The day does not exist in the given month, e.g. 30/2/2024 or 31/4/2024.
+    DayNotInMonth {
+        /// The rejected day.
+        day: u8,
+        /// The month the day was rejected for.
+        month: Month,
+        /// The year the day was rejected for (which matters for February).
+        year: Year,
+    },
+}
+
+impl Display for DateError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            RelativeTime::Directional { duration, dir } => write!(f, "{duration} {dir}"),
-            RelativeTime::Next(unit) => write!(f, "next {unit}"),
-            RelativeTime::Last(unit) => write!(f, "last {unit}"),
-            RelativeTime::Named(named) => write!(f, "{named}"),
+            DateError::InvalidMonth(month) => {
+                write!(f, "month must be between 1 and 12 (inclusive), got {month}")
+            }
+            DateError::InvalidDay(day) => {
+                write!(f, "day must be between 1 and 31 (inclusive), got {day}")
+            }
+            DateError::DayNotInMonth { day, month, year } => {
+                write!(f, "day {day} does not exist in {} {year}", month.name())
+            }
         }
     }
 }
 
-/// A `dd/mm/yyyy` style date.
+impl std::error::Error for DateError {}
+
+/// A `dd/mm/yyyy` style date. The month can also be given by name, in which case the `/`
+/// separators are optional, i.e. `20 April 2021`, `20 apr 2021` or `20/Apr/2021`.
+///
+/// If the first number has exactly four digits, the date is instead parsed year-first, as in
+/// `2021/4/20`, or ISO-8601 style with `-` separators, as in `2021-04-20`. Dates are always
+/// displayed in the `dd/mm/yyyy` form.
+///
+/// Since the fields are public, a [Date] can be constructed directly without any validation
+/// (e.g. with a `DayOfMonth(40)`). Prefer [Date::new], which checks that the day actually
+/// exists in the given month and year.
+///
+/// **Note:** the derived [Ord] and [PartialOrd] impls compare the fields in declaration order,
+/// i.e. month first, then day, then year, which is _not_ chronological (`1/1/2025` sorts
+/// before `31/12/2024`). Use [Date::cmp_chronological] to order dates in time.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Date(pub Month, pub DayOfMonth, pub Year);
 
 impl Parse for Date {
     fn parse(input: ParseStream) -> Result<Self> {
+        Date::parse_pivoted(input, None)
+    }
+}
+
+impl Date {
+    /// The pivot used by POSIX `strptime` for two-digit years, under which `00` to `68` denote
+    /// 2000 to 2068, and `69` to `99` denote 1969 to 1999. See [Date::parse_with_year_pivot].
+    pub const POSIX_YEAR_PIVOT: u8 = 69;
+
+    /// Parses a [Date] from `s` like the [FromStr] impl, except that a two-digit year in a
+    /// `dd/mm/yy` date is expanded to a full [Year]: years below `pivot` are taken to be in the
+    /// 2000s, and the rest in the 1900s (see [Date::POSIX_YEAR_PIVOT]). Years written with any
+    /// other number of digits are left as is.
+    ///
+    /// The standard [Parse] and [FromStr] impls never expand years, so `20/4/91` is in the
+    /// year 91.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let date = Date::parse_with_year_pivot("20/4/91", Date::POSIX_YEAR_PIVOT).unwrap();
+    /// assert_eq!(date.to_string(), "20/4/1991");
+    /// assert_eq!("20/4/91".parse::<Date>().unwrap().to_string(), "20/4/91");
+    /// ```
+    pub fn parse_with_year_pivot(s: &str, pivot: u8) -> std::result::Result<Date, ParseError> {
+        parse_str_with_offset(s, |input| Date::parse_pivoted(input, Some(pivot)))
+    }
+
+    /// Parses a [Date], expanding two-digit `dd/mm/yy` years using `pivot` if one is given.
+    fn parse_pivoted(input: ParseStream, pivot: Option<u8>) -> Result<Self> {
+        if Date::peek_iso(input) {
+            let (date, hour) = Date::parse_iso(input)?;
+            if let Some(hour) = hour {
+                return Err(Error::new(hour.span(), "unexpected time after date"));
+            }
+            return Ok(date);
+        }
+        let fork = input.fork();
+        if fork.parse::<LitInt>()?.base10_digits().len() == 4 && fork.peek(Token![/]) {
+            // yyyy/mm/dd
+            let year = input.parse::<Year>()?;
+            input.parse::<Token![/]>()?;
+            let month = input.parse::<Month>()?;
+            input.parse::<Token![/]>()?;
+            let day = input.parse::<DayOfMonth>()?;
+            return Ok(Date(month, day, year));
+        }
         let day = input.parse::<DayOfMonth>()?;
-        input.parse::<Token![/]>()?;
+        // separators are only optional when the month is spelled out
+        let named = input.peek(Ident) || (input.peek(Token![/]) && input.peek2(Ident));
+        if !named || input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+        }
         let month = input.parse::<Month>()?;
-        input.parse::<Token![/]>()?;
-        let year = input.parse::<Year>()?;
+        if !named || input.peek(Token![/]) {
+            input.parse::<Token![/]>()?;
+        }
+        let two_digits = input
+            .fork()
+            .parse::<LitInt>()
+            .is_ok_and(|lit| lit.to_string().len() - lit.suffix().len() == 2);
+        let year = match (input.parse::<Year>()?, pivot) {
+            (Year(year), Some(pivot)) if two_digits && year < pivot as u16 => Year(2000 + year),
+            (Year(year), Some(_)) if two_digits => Year(1900 + year),
+            (year, _) => year,
+        };
         Ok(Date(month, day, year))
     }
 }
@@ -888,17 +3972,271 @@ impl Display for Date {
     }
 }
 
+impl Date {
+    /// Whether the upcoming tokens look like the start of a [Date], i.e. a number followed by
+    /// either a `/` or a month name.
+    fn peek(input: ParseStream) -> bool {
+        if !input.peek(LitInt) {
+            return false;
+        }
+        if input.peek2(Token![/]) || Date::peek_iso(input) {
+            return true;
+        }
+        let fork = input.fork();
+        fork.parse::<LitInt>().is_ok()
+            && fork
+                .parse::<Ident>()
+                .is_ok_and(|ident| Month::from_name(&ident.to_string()).is_some())
+    }
+
+    /// Whether the upcoming tokens look like the start of an ISO-8601 style `yyyy-mm-dd` date,
+    /// i.e. a four-digit number followed by a `-`.
+    fn peek_iso(input: ParseStream) -> bool {
+        input.peek2(Token![-])
+            && input
+                .fork()
+                .parse::<LitInt>()
+                .is_ok_and(|lit| lit.suffix().is_empty() && lit.base10_digits().len() == 4)
+    }
+
+    /// Whether the upcoming tokens look like an ISO-8601 style `yyyy-mm-ddThh:mm` date-time.
+    fn peek_iso_date_time(input: ParseStream) -> bool {
+        Date::peek_iso(input) && matches!(Date::parse_iso(&input.fork()), Ok((_, Some(_))))
+    }
+
+    /// Parses an ISO-8601 style `yyyy-mm-dd` date. Because `20T14` lexes as a single integer
+    /// literal with a `T14` suffix, the hour of a `yyyy-mm-ddThh:mm` date-time ends up attached
+    /// to the day, so it is split off and returned separately for [DateTime] to pick up.
+    fn parse_iso(input: ParseStream) -> Result<(Date, Option<LitInt>)> {
+        let year = input.parse::<Year>()?;
+        input.parse::<Token![-]>()?;
+        let month = input.parse::<Month>()?;
+        input.parse::<Token![-]>()?;
+        let lit = input.parse::<LitInt>()?;
+        let suffix = lit.suffix();
+        let hour = match suffix.strip_prefix(['T', 't']) {
+            None if suffix.is_empty() => None,
+            Some(hour) if !hour.is_empty() && hour.bytes().all(|b| b.is_ascii_digit()) => {
+                Some(LitInt::new(hour, lit.span()))
+            }
+            _ => {
+                return Err(Error::new(
+                    lit.span(),
+                    "expected `T` followed by an hour after day",
+                ))
+            }
+        };
+        let day = lit.base10_parse::<u8>()?;
+        if day > 31 || day == 0 {
+            return Err(Error::new(
+                lit.span(),
+                "day must be between 1 and 31 (inclusive)",
+            ));
+        }
+        Ok((Date(month, DayOfMonth(day), year), hour))
+    }
+
+    /// Constructs a [Date] from a day, month (1-12) and year, validating that the month is in
+    /// range and that the day exists in that month (taking leap years into account).
+    ///
+    /// ```
+    /// use timelang::*;
+    /// const LEAP_DAY: Result<Date, DateError> = Date::new(29, 2, 2024);
+    /// assert_eq!(LEAP_DAY.unwrap().to_string(), "29/2/2024");
+    /// assert_eq!(
+    ///     Date::new(29, 2, 2023).unwrap_err().to_string(),
+    ///     "day 29 does not exist in February 2023"
+    /// );
+    /// ```
+    pub const fn new(day: u8, month: u8, year: u16) -> std::result::Result<Date, DateError> {
+        if month == 0 || month > 12 {
+            return Err(DateError::InvalidMonth(month));
+        }
+        if day == 0 || day > 31 {
+            return Err(DateError::InvalidDay(day));
+        }
+        let month = MONTHS[month as usize - 1];
+        if day > days_in_month(year as i64, month as u8) {
+            return Err(DateError::DayNotInMonth {
+                day,
+                month,
+                year: Year(year),
+            });
+        }
+        Ok(Date(month, DayOfMonth(day), Year(year)))
+    }
+
+    /// Compares this [Date] with `other` chronologically, i.e. by year, then month, then day,
+    /// unlike the derived [Ord] impl. Dates that don't exist (like `31/2/2024`) are still
+    /// ordered field by field.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// use std::cmp::Ordering;
+    /// let a = "1/1/2025".parse::<Date>().unwrap();
+    /// let b = "31/12/2024".parse::<Date>().unwrap();
+    /// assert_eq!(a.cmp_chronological(&b), Ordering::Greater);
+    /// assert!(a < b); // derived, month-first
+    /// ```
+    pub fn cmp_chronological(&self, other: &Date) -> Ordering {
+        (self.2, self.0, self.1).cmp(&(other.2, other.0, other.1))
+    }
+
+    /// The number of days since 1/1/1970.
+    fn days(&self) -> i64 {
+        days_from_civil(self.2 .0 as i64, self.0.into(), self.1 .0)
+    }
+
+    /// Renders this [Date] in the ISO-8601 `YYYY-MM-DD` form, zero padding each component.
+    /// Unlike the [Display] impl, this is meant for machine consumption.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let date = Date(Month::April, DayOfMonth(5), Year(2021));
+    /// assert_eq!(date.to_iso8601(), "2021-04-05");
+    /// assert_eq!(date.to_iso8601().parse::<Date>().unwrap(), date);
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.2 .0, u8::from(self.0), self.1 .0)
+    }
+
+    /// The [Weekday] this [Date] falls on.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!("15/3/2024".parse::<Date>().unwrap().weekday(), Weekday::Friday);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        WEEKDAYS[weekday(self.days()) as usize]
+    }
+
+    /// The [TimeRange] covering this entire [Date], i.e. from 0:00 to 23:59, as produced by
+    /// "all day [Date]" (see [TimeExpression::AllDay]).
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let date = "5/6/2024".parse::<Date>().unwrap();
+    /// assert_eq!(date.all_day().to_string(), "from 5/6/2024 at 0:00 to 5/6/2024 at 23:59");
+    /// ```
+    pub fn all_day(&self) -> TimeRange {
+        TimeRange(
+            PointInTime::Absolute(AbsoluteTime::DateTime(self.midnight())),
+            PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(
+                *self,
+                Time(Hour::Hour24(23), Minute(59)),
+            ))),
+        )
+    }
+
+    /// Parses the `all day` keywords.
+    fn parse_all_day(input: ParseStream) -> Result<()> {
+        for expected in ["all", "day"] {
+            let ident = input.parse::<Ident>()?;
+            if ident.to_string().to_lowercase() != expected {
+                return Err(Error::new(ident.span(), format!("expected `{expected}`")));
+            }
+        }
+        Ok(())
+    }
+
+    /// A [DateTime] corresponding with midnight at the start of this [Date].
+    fn midnight(&self) -> DateTime {
+        DateTime(*self, Time(Hour::Hour24(0), Minute(0)))
+    }
+
+    /// Adds `n` calendar months (which may be negative) to this [Date], using `policy` to
+    /// decide what happens when the day of the month does not exist in the resulting month
+    /// (e.g. adding one month to 31/1/2024). Returns `None` if the result is rejected by
+    /// `policy` or falls outside the range representable by [Year].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let date = "31/1/2024".parse::<Date>().unwrap();
+    /// assert_eq!(
+    ///     date.checked_add_months(1, MonthEndPolicy::Clamp),
+    ///     Some("29/2/2024".parse().unwrap())
+    /// );
+    /// assert_eq!(date.checked_add_months(1, MonthEndPolicy::Reject), None);
+    /// assert_eq!(
+    ///     date.checked_add_months(1, MonthEndPolicy::Overflow),
+    ///     Some("2/3/2024".parse().unwrap())
+    /// );
+    /// ```
+    pub fn checked_add_months(&self, n: i32, policy: MonthEndPolicy) -> Option<Date> {
+        let month = self.2 .0 as i64 * 12 + u8::from(self.0) as i64 - 1 + n as i64;
+        let (year, month) = (month.div_euclid(12), month.rem_euclid(12) as u8 + 1);
+        let year = u16::try_from(year).ok()?;
+        let day = self.1 .0;
+        let last_day = days_in_month(year as i64, month);
+        if day <= last_day {
+            return Some(Date(
+                MONTHS[month as usize - 1],
+                DayOfMonth(day),
+                Year(year),
+            ));
+        }
+        match policy {
+            MonthEndPolicy::Clamp => Some(Date(
+                MONTHS[month as usize - 1],
+                DayOfMonth(last_day),
+                Year(year),
+            )),
+            MonthEndPolicy::Reject => None,
+            MonthEndPolicy::Overflow => {
+                let days = days_from_civil(year as i64, month, last_day) + (day - last_day) as i64;
+                let (year, month, day) = civil_from_days(days);
+                Some(Date(
+                    MONTHS[month as usize - 1],
+                    DayOfMonth(day),
+                    Year(u16::try_from(year).ok()?),
+                ))
+            }
+        }
+    }
+}
+
+/// Determines how [Date::checked_add_months] handles a day of the month that does not exist in
+/// the resulting month, such as adding one month to 31/1/2024.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MonthEndPolicy {
+    /// Clamp to the last day of the resulting month (31/1/2024 + 1 month → 29/2/2024). This is
+    /// the policy used when resolving [Duration]s.
+    Clamp,
+    /// Reject the result, i.e. return `None`.
+    Reject,
+    /// Roll the excess days over into the following month (31/1/2024 + 1 month → 2/3/2024).
+    Overflow,
+}
+
 /// e.g. `22/4/1991 5:25 PM`, `22/4/1991 at 5:25 PM`, `22/4/1991 15:28`.
 ///
 /// Note that "at" is optional and time can either be 12-hour (must have am/pm specified) or
-/// 24-hour.
+/// 24-hour. ISO-8601 style date-times with a `T` separator, such as `2021-04-20T14:00`, are
+/// also accepted.
+///
+/// **Note:** like that of [Date], the derived [Ord] impl is not chronological, since it
+/// compares dates month-first and orders every [Hour::Hour12] before every [Hour::Hour24].
+/// Use [DateTime::cmp_chronological] to order date-times in time.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTime(pub Date, pub Time); // 22/4/1991 5:25 PM
 
 impl Parse for DateTime {
     fn parse(input: ParseStream) -> Result<Self> {
+        if Date::peek_iso_date_time(input) {
+            // yyyy-mm-ddThh:mm
+            if let (date, Some(hour)) = Date::parse_iso(input)? {
+                return Ok(DateTime(date, Time::parse_after_hour(input, hour)?));
+            }
+        }
         let date = input.parse::<Date>()?;
-        if input.peek(Ident) {
+        let keyword = input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| Time::from_keyword(&ident.to_string()).is_some());
+        if input.peek(Ident) && !keyword {
+            // optional `at`
             let ident = input.parse::<Ident>()?;
             if ident.to_string().to_lowercase().as_str() != "at" {
                 return Err(Error::new(ident.span(), "expected `at`"));
@@ -909,35 +4247,302 @@ impl Parse for DateTime {
     }
 }
 
+/// The alternate form (`{:#}`) omits the `at` separator, e.g. `22/4/1991 5:25 PM`. Both forms
+/// parse back to the same [DateTime].
 impl Display for DateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{} at {}", self.0, self.1))
+        match f.alternate() {
+            true => f.write_fmt(format_args!("{} {}", self.0, self.1)),
+            false => f.write_fmt(format_args!("{} at {}", self.0, self.1)),
+        }
+    }
+}
+
+impl Add<Duration> for DateTime {
+    type Output = DateTime;
+
+    /// Adds `rhs` to this [DateTime], applying years and months first as calendar months
+    /// (using [MonthEndPolicy::Clamp]) and then the remaining units, saturating at the bounds
+    /// representable by [Year].
+    fn add(self, rhs: Duration) -> Self::Output {
+        rhs.shift(self, true)
+    }
+}
+
+impl Add<SignedDuration> for DateTime {
+    type Output = DateTime;
+
+    /// Adds (or, if negative, subtracts) `rhs` to this [DateTime], with the same semantics as
+    /// adding a [Duration].
+    fn add(self, rhs: SignedDuration) -> Self::Output {
+        rhs.duration.shift(self, !rhs.negative)
+    }
+}
+
+impl DateTime {
+    /// Renders this [DateTime] in the ISO-8601 `YYYY-MM-DDTHH:MM` form, joining
+    /// [Date::to_iso8601] and [Time::to_iso8601] with a `T`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let dt = "5/4/2021 at 2:07 PM".parse::<DateTime>().unwrap();
+    /// assert_eq!(dt.to_iso8601(), "2021-04-05T14:07");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        format!("{}T{}", self.0.to_iso8601(), self.1.to_iso8601())
+    }
+
+    /// Compares this [DateTime] with `other` chronologically, unlike the derived [Ord] impl:
+    /// dates are compared via [Date::cmp_chronological], and then times are compared after
+    /// converting their hours to the 24-hour clock (see [Hour::to_hour24]), so that `1:00 AM`
+    /// comes before `13:00` and `1:00 PM` equals `13:00`. `24:00` is ordered after every other
+    /// time on the same date.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// use std::cmp::Ordering;
+    /// let a = "1/1/2025 at 1:00 PM".parse::<DateTime>().unwrap();
+    /// let b = "1/1/2025 at 13:00".parse::<DateTime>().unwrap();
+    /// assert_eq!(a.cmp_chronological(&b), Ordering::Equal);
+    /// ```
+    pub fn cmp_chronological(&self, other: &DateTime) -> Ordering {
+        self.0.cmp_chronological(&other.0).then_with(|| {
+            let key = |time: &Time| (time.0.to_hour24(), time.1);
+            key(&self.1).cmp(&key(&other.1))
+        })
+    }
+
+    /// Renders this [DateTime] like its [Display] impl, but with the time in 12-hour format
+    /// (see [Time::to_12h_string]). `24:00` is rendered as `12:00 AM` on the following day.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let dt = "5/6/2024 at 13:05".parse::<DateTime>().unwrap();
+    /// assert_eq!(dt.to_12h_string(), "5/6/2024 at 1:05 PM");
+    /// ```
+    pub fn to_12h_string(&self) -> String {
+        let DateTime(date, time) = match self.1.normalize() {
+            (_, true) => DateTime::from_minutes(self.minutes()),
+            _ => *self,
+        };
+        format!("{date} at {}", time.to_12h_string())
+    }
+
+    /// Renders this [DateTime] without the `at` separator inserted by its [Display] impl,
+    /// e.g. `5/6/2024 14:30` rather than `5/6/2024 at 14:30`. The parser accepts both forms.
+    /// Equivalent to formatting with `{:#}`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let dt = "5/6/2024 at 14:30".parse::<DateTime>().unwrap();
+    /// assert_eq!(dt.to_string_no_at(), "5/6/2024 14:30");
+    /// assert_eq!(dt.to_string_no_at().parse::<DateTime>().unwrap(), dt);
+    /// ```
+    pub fn to_string_no_at(&self) -> String {
+        format!("{self:#}")
+    }
+
+    /// Attaches `offset` to this (naive) [DateTime], interpreting its wall-clock time as being
+    /// observed at that offset. No conversion takes place; see [OffsetDateTime::to_offset] for
+    /// that.
+    pub fn with_offset(&self, offset: UtcOffset) -> OffsetDateTime {
+        OffsetDateTime(*self, offset)
+    }
+
+    /// Like [Date::checked_add_months], but preserves the [Time] of this [DateTime].
+    pub fn checked_add_months(&self, n: i32, policy: MonthEndPolicy) -> Option<DateTime> {
+        Some(DateTime(self.0.checked_add_months(n, policy)?, self.1))
+    }
+
+    /// The number of minutes since midnight on 1/1/1970.
+    fn minutes(&self) -> i128 {
+        self.0.days() as i128 * MINUTES_PER_DAY + self.1.minute_of_day() as i128
+    }
+
+    /// Constructs a 24-hour [DateTime] from a number of minutes since midnight on 1/1/1970,
+    /// saturating at the bounds representable by [Year].
+    fn from_minutes(minutes: i128) -> DateTime {
+        let min = days_from_civil(0, 1, 1) as i128 * MINUTES_PER_DAY;
+        let max = days_from_civil(u16::MAX as i64, 12, 31) as i128 * MINUTES_PER_DAY
+            + MINUTES_PER_DAY
+            - 1;
+        let minutes = minutes.clamp(min, max);
+        let (year, month, day) = civil_from_days(minutes.div_euclid(MINUTES_PER_DAY) as i64);
+        let minute_of_day = minutes.rem_euclid(MINUTES_PER_DAY) as u16;
+        DateTime(
+            Date(
+                MONTHS[month as usize - 1],
+                DayOfMonth(day),
+                Year(year as u16),
+            ),
+            Time(
+                Hour::Hour24((minute_of_day / 60) as u8),
+                Minute((minute_of_day % 60) as u8),
+            ),
+        )
+    }
+}
+
+/// A [DateTime] observed at a particular [UtcOffset], e.g. `22/4/1991 at 14:30 +02:00` or
+/// `22/4/1991 5:25 PM UTC`.
+///
+/// A plain [DateTime] is naive, i.e. it carries no offset. Use [DateTime::with_offset] to
+/// attach one, which treats the naive wall-clock time as already being in that offset.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetDateTime(pub DateTime, pub UtcOffset);
+
+impl Parse for OffsetDateTime {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(OffsetDateTime(input.parse()?, input.parse()?))
+    }
+}
+
+impl Display for OffsetDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.0, self.1)
+    }
+}
+
+impl OffsetDateTime {
+    /// Converts this [OffsetDateTime] to the same instant as observed at `offset`, adjusting
+    /// the wall-clock time (and date, if a day boundary is crossed) accordingly. The resulting
+    /// [Time] is always 24-hour, and saturates at the bounds representable by [Year].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let date_time = "1/1/2024 at 14:30 +02:00".parse::<OffsetDateTime>().unwrap();
+    /// assert_eq!(
+    ///     date_time.to_offset(UtcOffset(-5 * 60)).to_string(),
+    ///     "1/1/2024 at 7:30 -05:00"
+    /// );
+    /// ```
+    pub fn to_offset(&self, offset: UtcOffset) -> OffsetDateTime {
+        let minutes = self.0.minutes() - self.1 .0 as i128 + offset.0 as i128;
+        OffsetDateTime(DateTime::from_minutes(minutes), offset)
+    }
+
+    /// Converts this [OffsetDateTime] to UTC, i.e. `+00:00`. See [OffsetDateTime::to_offset].
+    pub fn to_utc(&self) -> OffsetDateTime {
+        self.to_offset(UtcOffset(0))
+    }
+}
+
+/// A [Time] optionally followed by a [UtcOffset], e.g. `14:00+02:00`, `14:00Z`, or a bare
+/// `14:00` (with no offset), as commonly found in log output.
+///
+/// When present, the offset is displayed in the `+HH:MM` form after a space, as in
+/// `14:00 +02:00`. Since a time alone cannot be converted across a day boundary, use
+/// [OffsetTime::with_date] to obtain an [OffsetDateTime] that can be converted to UTC.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OffsetTime(pub Time, pub Option<UtcOffset>);
+
+impl Parse for OffsetTime {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let time = input.parse()?;
+        let offset = match UtcOffset::peek(input) {
+            true => Some(input.parse()?),
+            false => None,
+        };
+        Ok(OffsetTime(time, offset))
+    }
+}
+
+impl Display for OffsetTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.1 {
+            Some(offset) => write!(f, "{} {}", self.0, offset),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl OffsetTime {
+    /// Combines this [OffsetTime] with `date` to form an [OffsetDateTime], or returns `None` if
+    /// no offset was specified.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let time = "23:30-05:00".parse::<OffsetTime>().unwrap();
+    /// let date = "31/12/2023".parse::<Date>().unwrap();
+    /// assert_eq!(
+    ///     time.with_date(date).unwrap().to_utc().to_string(),
+    ///     "1/1/2024 at 4:30 +00:00"
+    /// );
+    /// ```
+    pub fn with_date(&self, date: Date) -> Option<OffsetDateTime> {
+        Some(OffsetDateTime(DateTime(date, self.0), self.1?))
     }
 }
 
 /// A simple representation of the time, e.g. `13:07` or `5:07 PM`.
 ///
 /// Both 24-hour and 12-hour are supported (must specify `AM` or `PM` when using 12-hour).
+/// 12-hour times may omit the minutes, as in `5 PM`, which is displayed as `5:00 PM`.
+/// When parsing from a string (via [FromStr] or [TimeExpression::parse_lenient]), `AM` / `PM`
+/// may also be attached directly to the minutes, as in `5:30PM`.
+///
+/// `24:00` is accepted to denote midnight at the end of the day (see [Time::normalize]), but
+/// the hour `24` cannot be combined with any other minute.
+///
+/// The keywords `noon` and `midnight` are also accepted, and are equivalent to `12:00` and
+/// `0:00` respectively (which is also how they are displayed).
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Time(pub Hour, pub Minute);
 
 impl Parse for Time {
     fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Ident) {
+            let ident = input.parse::<Ident>()?;
+            return Time::from_keyword(&ident.to_string())
+                .ok_or_else(|| Error::new(ident.span(), "expected [time], `noon` or `midnight`"));
+        }
+        Time::reject_duration(input)?;
         let hour_lit = input.parse::<LitInt>()?;
+        if !input.peek(Token![:]) && AmPm::peek(input) {
+            // `5 PM`, with the minutes left implicit
+            let hour_val = hour_lit.base10_parse::<u8>()?;
+            let am_pm = input.parse::<AmPm>()?;
+            if hour_val > 12 || hour_val == 0 {
+                return Err(Error::new(
+                    hour_lit.span(),
+                    "hour must be between 1 and 12 (inclusive)",
+                ));
+            }
+            return Ok(Time(Hour::Hour12(hour_val, am_pm), Minute(0)));
+        }
+        Time::parse_after_hour(input, hour_lit)
+    }
+}
+
+impl Time {
+    /// Fails with a targeted error if the upcoming tokens, found where a [Time] (or one of its
+    /// numeric components) is expected, look like a [Duration] instead, as in `at 5h` or
+    /// `at 5 hours`. The error points at the offending number without consuming it.
+    fn reject_duration(input: ParseStream) -> Result<()> {
+        let fork = input.fork();
+        let Ok(lit) = fork.parse::<LitInt>() else {
+            return Ok(());
+        };
+        let suffix = lit.suffix();
+        let unit_suffix = matches!(suffix, "h" | "H" | "m" | "M" | "d" | "D" | "w" | "W")
+            || TimeUnit::from_name(suffix).is_some();
+        if unit_suffix || Duration::parse_unit(&fork).is_ok() {
+            return Err(Error::new(lit.span(), "expected a time, found a duration"));
+        }
+        Ok(())
+    }
+
+    /// Parses the remainder of a numeric [Time] (`:mm` and an optional am/pm) once its hour
+    /// literal has already been consumed.
+    fn parse_after_hour(input: ParseStream, hour_lit: LitInt) -> Result<Self> {
         let hour_val = hour_lit.base10_parse::<u8>()?;
         input.parse::<Token![:]>()?;
+        Time::reject_duration(input)?;
         let min = input.parse::<Minute>()?;
-        if input.peek(Ident)
-            && ["am", "pm"].contains(
-                &input
-                    .fork()
-                    .parse::<Ident>()
-                    .unwrap()
-                    .to_string()
-                    .to_lowercase()
-                    .as_str(),
-            )
-        {
+        if AmPm::peek(input) {
             let am_pm = input.parse::<AmPm>()?;
             if hour_val > 12 || hour_val == 0 {
                 return Err(Error::new(
@@ -947,29 +4552,249 @@ impl Parse for Time {
             }
             return Ok(Time(Hour::Hour12(hour_val, am_pm), min));
         }
-        if hour_val > 24 {
-            return Err(Error::new(
-                hour_lit.span(),
-                "hour must be between 0 and 24 (inclusive)",
-            ));
+        if hour_val > 24 {
+            return Err(Error::new(
+                hour_lit.span(),
+                "hour must be between 0 and 24 (inclusive)",
+            ));
+        }
+        if hour_val == 24 && min.0 != 0 {
+            return Err(Error::new(
+                hour_lit.span(),
+                "minute must be 00 when hour is 24",
+            ));
+        }
+        Ok(Time(Hour::Hour24(hour_val), min))
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Time(Hour::Hour12(hour, am_pm), minute) => {
+                write!(f, "{}:{:02} {}", hour, minute, am_pm)
+            }
+            Time(Hour::Hour24(hour), minute) => write!(f, "{}:{:02}", hour, minute),
+        }
+    }
+}
+
+impl Time {
+    /// The number of minutes since midnight.
+    fn minute_of_day(&self) -> u16 {
+        self.0.to_hour24() as u16 * 60 + self.1 .0 as u16
+    }
+
+    /// Normalizes `24:00` (midnight at the end of the day) to `0:00`, returning the normalized
+    /// [Time] along with a flag indicating whether the time was carried over into the next day.
+    /// All other times are returned unchanged, with the flag set to `false`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let (time, next_day) = "24:00".parse::<Time>().unwrap().normalize();
+    /// assert_eq!(time.to_string(), "0:00");
+    /// assert!(next_day);
+    /// ```
+    pub fn normalize(&self) -> (Time, bool) {
+        match self {
+            Time(Hour::Hour24(24), _) => (Time(Hour::Hour24(0), self.1), true),
+            _ => (*self, false),
+        }
+    }
+
+    /// Renders this [Time] in the ISO-8601 `HH:MM` form, always using the 24-hour clock with
+    /// zero padding. `24:00` (the end of the day) is rendered as is.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!("9:05 PM".parse::<Time>().unwrap().to_iso8601(), "21:05");
+    /// assert_eq!("12:30 AM".parse::<Time>().unwrap().to_iso8601(), "00:30");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        format!("{:02}:{:02}", self.0.to_hour24(), self.1 .0)
+    }
+
+    /// Returns `true` if this [Time] and `other` denote the same time of day, regardless of
+    /// whether they are expressed in 12-hour or 24-hour format (unlike the derived [PartialEq]
+    /// impl, which compares them structurally). Note that `24:00` (the end of the day) is not
+    /// considered the same as `0:00`; see [Time::normalize].
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let pm = "2:30 PM".parse::<Time>().unwrap();
+    /// let h24 = "14:30".parse::<Time>().unwrap();
+    /// assert_ne!(pm, h24);
+    /// assert!(pm.same_instant(&h24));
+    /// ```
+    pub fn same_instant(&self, other: &Time) -> bool {
+        self.minute_of_day() == other.minute_of_day()
+    }
+
+    /// Looks up the [Time] corresponding with one of the keywords `noon` (12:00) or `midnight`
+    /// (0:00), case-insensitively.
+    fn from_keyword(keyword: &str) -> Option<Time> {
+        match keyword.to_lowercase().as_str() {
+            "noon" => Some(Time(Hour::Hour24(12), Minute(0))),
+            "midnight" => Some(Time(Hour::Hour24(0), Minute(0))),
+            _ => None,
+        }
+    }
+
+    /// Whether the upcoming tokens are an hour followed by `AM` / `PM`, like `5 PM`, optionally
+    /// preceded by `at`.
+    fn peek_hour_am_pm(input: ParseStream) -> bool {
+        let fork = input.fork();
+        if fork.peek(Ident) && fork.parse::<Ident>().unwrap().to_string().to_lowercase() != "at" {
+            return false;
+        }
+        fork.parse::<LitInt>().is_ok() && AmPm::peek(&fork)
+    }
+
+    /// Whether the upcoming tokens are a time keyword like `noon`, optionally preceded by `at`.
+    fn peek_keyword(input: ParseStream) -> bool {
+        let fork = input.fork();
+        let mut ident = fork.parse::<Ident>();
+        if ident
+            .as_ref()
+            .is_ok_and(|ident| ident.to_string().to_lowercase() == "at")
+        {
+            ident = fork.parse::<Ident>();
+        }
+        ident.is_ok_and(|ident| Time::from_keyword(&ident.to_string()).is_some())
+    }
+
+    /// Returns a wrapper that displays this [Time] in 12-hour format (e.g. `2:30 PM`),
+    /// regardless of whether it was originally specified in 12-hour or 24-hour format.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let time = "14:30".parse::<Time>().unwrap();
+    /// assert_eq!(time.display_12h().to_string(), "2:30 PM");
+    /// ```
+    pub fn display_12h(&self) -> Display12h {
+        Display12h(*self)
+    }
+
+    /// Renders this [Time] in 12-hour format with `AM` / `PM`, for user-facing output, without
+    /// affecting the [Display] impl. Noon is `12:00 PM`, and midnight (including `24:00`) is
+    /// `12:00 AM`. Equivalent to `self.display_12h().to_string()`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!("13:00".parse::<Time>().unwrap().to_12h_string(), "1:00 PM");
+    /// assert_eq!("0:00".parse::<Time>().unwrap().to_12h_string(), "12:00 AM");
+    /// ```
+    pub fn to_12h_string(&self) -> String {
+        self.display_12h().to_string()
+    }
+
+    /// Returns a wrapper that displays this [Time] in 24-hour format (e.g. `14:30`),
+    /// regardless of whether it was originally specified in 12-hour or 24-hour format.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// let time = "2:30 PM".parse::<Time>().unwrap();
+    /// assert_eq!(time.display_24h().to_string(), "14:30");
+    /// ```
+    pub fn display_24h(&self) -> Display24h {
+        Display24h(*self)
+    }
+}
+
+/// Displays the wrapped [Time] in 12-hour format. See [Time::display_12h].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Display12h(pub Time);
+
+impl Display for Display12h {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (hour, am_pm) = self.0 .0.to_hour12();
+        write!(f, "{}:{:02} {}", hour, self.0 .1, am_pm)
+    }
+}
+
+/// Displays the wrapped [Time] in 24-hour format. See [Time::display_24h].
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct Display24h(pub Time);
+
+impl Display for Display24h {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{:02}", self.0 .0.to_hour24(), self.0 .1)
+    }
+}
+
+/// How precisely a [QualifiedTime] is meant to be observed.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Precision {
+    /// No qualifier was given, e.g. "3:00 PM".
+    Unqualified,
+    /// The time is exact, e.g. "3:00 PM sharp" or "exactly 15:00".
+    Exact,
+}
+
+/// A [Time] along with an optional exactness qualifier, i.e. a leading `exactly` and/or a
+/// trailing `sharp`, as in "3:00 PM sharp" or "exactly 14:00". The qualifier is recorded as a
+/// [Precision] and does not affect the [Time] itself.
+///
+/// [Precision::Exact] is always displayed in the trailing `sharp` form.
+///
+/// Within larger expressions, qualified times are accepted by [RelativeTime::NamedAt], as in
+/// "tomorrow at 3 PM sharp". Absolute [DateTime]s and clock ranges do not take qualifiers.
+///
+/// ```
+/// use timelang::*;
+/// let time = "exactly 15:00".parse::<QualifiedTime>().unwrap();
+/// assert_eq!(time.1, Precision::Exact);
+/// assert_eq!(time.to_string(), "15:00 sharp");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QualifiedTime(pub Time, pub Precision);
+
+impl Parse for QualifiedTime {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let peek_word = |input: ParseStream, word: &str| {
+            input
+                .fork()
+                .parse::<Ident>()
+                .is_ok_and(|ident| ident.to_string().to_lowercase() == word)
+        };
+        let mut precision = Precision::Unqualified;
+        if peek_word(input, "exactly") {
+            input.parse::<Ident>()?;
+            precision = Precision::Exact;
+        }
+        let time = input.parse::<Time>()?;
+        if peek_word(input, "sharp") {
+            input.parse::<Ident>()?;
+            precision = Precision::Exact;
         }
-        Ok(Time(Hour::Hour24(hour_val), min))
+        Ok(QualifiedTime(time, precision))
     }
 }
 
-impl Display for Time {
+impl From<Time> for QualifiedTime {
+    /// Wraps `time` as [Precision::Unqualified].
+    fn from(time: Time) -> Self {
+        QualifiedTime(time, Precision::Unqualified)
+    }
+}
+
+impl Display for QualifiedTime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Time(Hour::Hour12(hour, am_pm), minute) => {
-                write!(f, "{}:{:02} {}", hour, minute, am_pm)
-            }
-            Time(Hour::Hour24(hour), minute) => write!(f, "{}:{:02}", hour, minute),
+        match self.1 {
+            Precision::Unqualified => write!(f, "{}", self.0),
+            Precision::Exact => write!(f, "{} sharp", self.0),
         }
     }
 }
 
 /// Represents a particular day of the month, which can range from 1 to 31.
+///
+/// When parsing, the number may be followed immediately by an ordinal suffix such as `1st` or
+/// `22nd`. The suffix is not checked against the number, and is never displayed.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DayOfMonth(pub u8);
 
 impl Parse for DayOfMonth {
@@ -982,6 +4807,13 @@ impl Parse for DayOfMonth {
                 "day must be between 1 and 31 (inclusive)",
             ));
         }
+        // optional ordinal suffix, e.g. `22nd`
+        if !["", "st", "nd", "rd", "th"].contains(&lit.suffix().to_lowercase().as_str()) {
+            return Err(Error::new(
+                lit.span(),
+                "expected one of `st`, `nd`, `rd`, `th` after day",
+            ));
+        }
         Ok(DayOfMonth(int_val))
     }
 }
@@ -994,13 +4826,20 @@ impl Display for DayOfMonth {
 
 /// Represents a year, which can be any valid [`u16`].
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Year(pub u16);
 
 impl Parse for Year {
     fn parse(input: ParseStream) -> Result<Self> {
         let lit = input.parse::<LitInt>()?;
-        let int_val = lit.base10_parse::<u16>()?;
-        Ok(Year(int_val))
+        let int_val = lit.base10_parse::<u64>()?;
+        if int_val > u16::MAX as u64 {
+            return Err(Error::new(
+                lit.span(),
+                format!("year must be between 0 and {} (inclusive)", u16::MAX),
+            ));
+        }
+        Ok(Year(int_val as u16))
     }
 }
 
@@ -1012,6 +4851,7 @@ impl Display for Year {
 
 /// Represents an hour of the day in either 12-hour or 24-hour format.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Hour {
     /// 12-hour format, i.e. `5 PM`
     Hour12(u8, AmPm),
@@ -1051,18 +4891,44 @@ impl Display for Hour {
     }
 }
 
-/// Represents a minute of the hour, which can range from 0 to 60.
+impl Hour {
+    /// Converts this [Hour] to its 24-hour equivalent, e.g. `12 AM` → `0`, `12 PM` → `12`,
+    /// `1 PM` → `13`.
+    pub fn to_hour24(&self) -> u8 {
+        match *self {
+            Hour::Hour12(12, AmPm::AM) => 0,
+            Hour::Hour12(12, AmPm::PM) => 12,
+            Hour::Hour12(hour, AmPm::AM) => hour,
+            Hour::Hour12(hour, AmPm::PM) => hour + 12,
+            Hour::Hour24(hour) => hour,
+        }
+    }
+
+    /// Converts this [Hour] to its 12-hour equivalent, e.g. `0` → `12 AM`, `12` → `12 PM`,
+    /// `13` → `1 PM`. Note that `24` (end of day) converts to `12 AM`.
+    pub fn to_hour12(&self) -> (u8, AmPm) {
+        match self.to_hour24() % 24 {
+            0 => (12, AmPm::AM),
+            12 => (12, AmPm::PM),
+            hour if hour > 12 => (hour - 12, AmPm::PM),
+            hour => (hour, AmPm::AM),
+        }
+    }
+}
+
+/// Represents a minute of the hour, which can range from 0 to 59.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Minute(pub u8);
 
 impl Parse for Minute {
     fn parse(input: ParseStream) -> Result<Self> {
         let lit = input.parse::<LitInt>()?;
         let int_val = lit.base10_parse::<u8>()?;
-        if int_val > 60 {
+        if int_val > 59 {
             return Err(Error::new(
                 lit.span(),
-                "minute must be between 0 and 60 (inclusive)",
+                "minute must be between 0 and 59 (inclusive)",
             ));
         }
         Ok(Minute(int_val))
@@ -1077,6 +4943,7 @@ impl Display for Minute {
 
 /// Represents a particular month of the year, which can range from 1-12
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Month {
     /// January (1)
@@ -1105,8 +4972,110 @@ pub enum Month {
     December,
 }
 
+const MONTH_ALIASES: [(&str, Month); 23] = [
+    ("january", Month::January),
+    ("jan", Month::January),
+    ("february", Month::February),
+    ("feb", Month::February),
+    ("march", Month::March),
+    ("mar", Month::March),
+    ("april", Month::April),
+    ("apr", Month::April),
+    ("may", Month::May),
+    ("june", Month::June),
+    ("jun", Month::June),
+    ("july", Month::July),
+    ("jul", Month::July),
+    ("august", Month::August),
+    ("aug", Month::August),
+    ("september", Month::September),
+    ("sep", Month::September),
+    ("october", Month::October),
+    ("oct", Month::October),
+    ("november", Month::November),
+    ("nov", Month::November),
+    ("december", Month::December),
+    ("dec", Month::December),
+];
+
+impl Month {
+    /// Every [Month], from January to December.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(Month::all().len(), 12);
+    /// assert_eq!(Month::all()[11], Month::December);
+    /// ```
+    pub const fn all() -> &'static [Month] {
+        &MONTHS
+    }
+
+    /// Every (lowercase) month name accepted when parsing a [Month], i.e. the full English
+    /// name and three-letter abbreviation of each month, paired with the [Month] it denotes.
+    /// Parsing is case-insensitive, and months may also be given by number.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert!(Month::all_aliases().contains(&("sep", Month::September)));
+    /// ```
+    pub const fn all_aliases() -> &'static [(&'static str, Month)] {
+        &MONTH_ALIASES
+    }
+
+    /// Looks up a month by its full English name or three-letter abbreviation
+    /// (case-insensitive).
+    fn from_name(name: &str) -> Option<Month> {
+        find_alias(&MONTH_ALIASES, name)
+    }
+
+    /// The full English name of this [Month], e.g. `"April"`.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(Month::April.name(), "April");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        use Month::*;
+        match self {
+            January => "January",
+            February => "February",
+            March => "March",
+            April => "April",
+            May => "May",
+            June => "June",
+            July => "July",
+            August => "August",
+            September => "September",
+            October => "October",
+            November => "November",
+            December => "December",
+        }
+    }
+}
+
+/// Displays the wrapped [Month] by its full English name (see [Month::name]), rather than
+/// numerically as the [Display] impl on [Month] does.
+///
+/// ```
+/// use timelang::*;
+/// assert_eq!(MonthName(Month::April).to_string(), "April");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct MonthName(pub Month);
+
+impl Display for MonthName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0.name())
+    }
+}
+
 impl Parse for Month {
     fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Ident) {
+            let ident = input.parse::<Ident>()?;
+            return Month::from_name(&ident.to_string())
+                .ok_or_else(|| Error::new(ident.span(), "expected month name"));
+        }
         let lit = input.parse::<LitInt>()?;
         let int_val = lit.base10_parse::<u8>()?;
         if int_val > 12 || int_val == 0 {
@@ -1167,8 +5136,80 @@ impl Display for Month {
     }
 }
 
+const MONTHS: [Month; 12] = [
+    Month::January,
+    Month::February,
+    Month::March,
+    Month::April,
+    Month::May,
+    Month::June,
+    Month::July,
+    Month::August,
+    Month::September,
+    Month::October,
+    Month::November,
+    Month::December,
+];
+
+const MINUTES_PER_DAY: i128 = 24 * 60;
+
+/// Upper bounds on the [Display] lengths of various fixed-size components, used by
+/// [TimeExpression::display_len_hint].
+const DATE_LEN_HINT: usize = 3 + 1 + 2 + 1 + 5; // 255/12/65535
+const TIME_LEN_HINT: usize = 3 + 1 + 3 + 3; // 255:255 PM
+const DATE_TIME_LEN_HINT: usize = DATE_LEN_HINT + " at ".len() + TIME_LEN_HINT;
+const NAMED_LEN_HINT: usize = "the day before yesterday".len();
+const UNIT_LEN_HINT: usize = "Wednesday".len();
+const DIRECTION_LEN_HINT: usize = "before ".len() + DATE_TIME_LEN_HINT;
+
+const fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The number of days between 1/1/1970 and the specified proleptic Gregorian date (see
+/// <http://howardhinnant.github.io/date_algorithms.html>).
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The inverse of [days_from_civil], returning a `(year, month, day)` triple.
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = (mp + if mp < 10 { 3 } else { -9 }) as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// The index of the specified day (in days since 1/1/1970) within its week (Monday = 0).
+fn weekday(days: i64) -> i64 {
+    (days + 3).rem_euclid(7)
+}
+
 /// Represents either AM or PM
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AmPm {
     /// AM
     AM,
@@ -1187,6 +5228,13 @@ impl Parse for AmPm {
     }
 }
 
+impl AmPm {
+    /// Whether the upcoming token is `AM` or `PM` (case-insensitive).
+    fn peek(input: ParseStream) -> bool {
+        input.fork().parse::<AmPm>().is_ok()
+    }
+}
+
 impl Display for AmPm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1205,8 +5253,101 @@ impl AsRef<str> for AmPm {
     }
 }
 
+/// Represents an offset from UTC, stored as a signed number of minutes.
+///
+/// Parses from `+HH:MM` / `-HH:MM`, `UTC`, `GMT`, `Z`, or one of a small set of common time
+/// zone abbreviations (`EST`, `EDT`, `CST`, `CDT`, `MST`, `MDT`, `PST`, `PDT`, `CET`, `CEST`,
+/// `EET`, `EEST`, `JST`, `AEST`, `AEDT`). Named locations such as cities are not supported, as
+/// they would require a time zone database. Always displays in the `+HH:MM` form.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UtcOffset(pub i16);
+
+impl UtcOffset {
+    /// Looks up one of the supported time zone abbreviations (case-insensitive).
+    fn from_abbreviation(abbreviation: &str) -> Option<UtcOffset> {
+        let hours = match abbreviation.to_lowercase().as_str() {
+            "utc" | "gmt" | "z" => 0,
+            "est" | "cdt" => -5,
+            "edt" => -4,
+            "cst" | "mdt" => -6,
+            "mst" | "pdt" => -7,
+            "pst" => -8,
+            "cet" => 1,
+            "cest" | "eet" => 2,
+            "eest" => 3,
+            "jst" => 9,
+            "aest" => 10,
+            "aedt" => 11,
+            _ => return None,
+        };
+        Some(UtcOffset(hours * 60))
+    }
+
+    /// Returns `true` if the next tokens in `input` look like a [UtcOffset].
+    fn peek(input: ParseStream) -> bool {
+        if (input.peek(Token![+]) || input.peek(Token![-]))
+            && input.peek2(LitInt)
+            && input.peek3(Token![:])
+        {
+            return true;
+        }
+        input.peek(Ident)
+            && UtcOffset::from_abbreviation(&input.fork().parse::<Ident>().unwrap().to_string())
+                .is_some()
+    }
+}
+
+impl Parse for UtcOffset {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Ident) {
+            let ident = input.parse::<Ident>()?;
+            return UtcOffset::from_abbreviation(&ident.to_string()).ok_or_else(|| {
+                Error::new(
+                    ident.span(),
+                    "expected `+HH:MM`, `-HH:MM`, `UTC`, or a known time zone abbreviation",
+                )
+            });
+        }
+        let sign = if input.peek(Token![-]) {
+            input.parse::<Token![-]>()?;
+            -1
+        } else {
+            input.parse::<Token![+]>()?;
+            1
+        };
+        let hours_lit = input.parse::<LitInt>()?;
+        let hours = hours_lit.base10_parse::<i16>()?;
+        if hours > 14 {
+            return Err(Error::new(
+                hours_lit.span(),
+                "offset hours must be between 0 and 14 (inclusive)",
+            ));
+        }
+        input.parse::<Token![:]>()?;
+        let minutes_lit = input.parse::<LitInt>()?;
+        let minutes = minutes_lit.base10_parse::<i16>()?;
+        if minutes > 59 {
+            return Err(Error::new(
+                minutes_lit.span(),
+                "offset minutes must be between 0 and 59 (inclusive)",
+            ));
+        }
+        Ok(UtcOffset(sign * (hours * 60 + minutes)))
+    }
+}
+
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let minutes = self.0.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
 /// Represents particular units of time, such as hours, minutes, etc.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeUnit {
     /// Minutes
     Minutes,
@@ -1225,31 +5366,106 @@ pub enum TimeUnit {
 impl Parse for TimeUnit {
     fn parse(input: ParseStream) -> Result<Self> {
         let ident = input.parse::<Ident>()?;
-        use TimeUnit::*;
-        Ok(match ident.to_string().to_lowercase().as_str() {
-            "mins" | "minutes" | "minute" | "min" => Minutes,
-            "hours" | "hrs" | "hour" | "hr" => Hours,
-            "days" | "day" => Days,
-            "weeks" | "week" => Weeks,
-            "months" | "month" => Months,
-            "years" | "yr" | "year" => Years,
-            _ => {
-                return Err(Error::new(
-                    ident.span(),
-                    "expected one of `minutes`, `hours`, `days`, `weeks`, `months`, and `years`",
-                ))
-            }
+        TimeUnit::from_name(&ident.to_string()).ok_or_else(|| {
+            Error::new(
+                ident.span(),
+                "expected one of `minutes`, `hours`, `days`, `weeks`, `months`, and `years`",
+            )
         })
     }
 }
 
+const TIME_UNITS: [TimeUnit; 6] = [
+    TimeUnit::Minutes,
+    TimeUnit::Hours,
+    TimeUnit::Days,
+    TimeUnit::Weeks,
+    TimeUnit::Months,
+    TimeUnit::Years,
+];
+
+const TIME_UNIT_ALIASES: [(&str, TimeUnit); 17] = [
+    ("minutes", TimeUnit::Minutes),
+    ("minute", TimeUnit::Minutes),
+    ("mins", TimeUnit::Minutes),
+    ("min", TimeUnit::Minutes),
+    ("hours", TimeUnit::Hours),
+    ("hour", TimeUnit::Hours),
+    ("hrs", TimeUnit::Hours),
+    ("hr", TimeUnit::Hours),
+    ("days", TimeUnit::Days),
+    ("day", TimeUnit::Days),
+    ("weeks", TimeUnit::Weeks),
+    ("week", TimeUnit::Weeks),
+    ("months", TimeUnit::Months),
+    ("month", TimeUnit::Months),
+    ("years", TimeUnit::Years),
+    ("year", TimeUnit::Years),
+    ("yr", TimeUnit::Years),
+];
+
+impl TimeUnit {
+    /// Every [TimeUnit], from smallest to largest.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(TimeUnit::all().len(), 6);
+    /// assert_eq!(TimeUnit::all()[0], TimeUnit::Minutes);
+    /// ```
+    pub const fn all() -> &'static [TimeUnit] {
+        &TIME_UNITS
+    }
+
+    /// Every (lowercase) spelling accepted when parsing a [TimeUnit], such as `min`, `mins`,
+    /// `minute` and `minutes`, paired with the [TimeUnit] it denotes. Parsing is
+    /// case-insensitive.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert!(TimeUnit::all_aliases().contains(&("hrs", TimeUnit::Hours)));
+    /// ```
+    pub const fn all_aliases() -> &'static [(&'static str, TimeUnit)] {
+        &TIME_UNIT_ALIASES
+    }
+
+    /// Looks up a [TimeUnit] by (case-insensitive) name or abbreviation, e.g. `hours` or `hr`.
+    fn from_name(name: &str) -> Option<TimeUnit> {
+        find_alias(&TIME_UNIT_ALIASES, name)
+    }
+
+    /// The correctly pluralized word for `count` of this [TimeUnit], i.e. the singular form
+    /// (e.g. `hour`) when `count` is 1, and the plural form (e.g. `hours`) otherwise.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(TimeUnit::Hours.label(1), "hour");
+    /// assert_eq!(TimeUnit::Hours.label(2), "hours");
+    /// ```
+    pub fn label(&self, count: u64) -> &'static str {
+        match (self, count) {
+            (TimeUnit::Minutes, 1) => "minute",
+            (TimeUnit::Hours, 1) => "hour",
+            (TimeUnit::Days, 1) => "day",
+            (TimeUnit::Weeks, 1) => "week",
+            (TimeUnit::Months, 1) => "month",
+            (TimeUnit::Years, 1) => "year",
+            (TimeUnit::Minutes, _) => "minutes",
+            (TimeUnit::Hours, _) => "hours",
+            (TimeUnit::Days, _) => "days",
+            (TimeUnit::Weeks, _) => "weeks",
+            (TimeUnit::Months, _) => "months",
+            (TimeUnit::Years, _) => "years",
+        }
+    }
+}
+
 impl AsRef<str> for TimeUnit {
     fn as_ref(&self) -> &str {
         match self {
             TimeUnit::Minutes => "minutes",
             TimeUnit::Hours => "hours",
             TimeUnit::Days => "days",
-            TimeUnit::Weeks => "minutes",
+            TimeUnit::Weeks => "weeks",
             TimeUnit::Months => "months",
             TimeUnit::Years => "years",
         }
@@ -1263,7 +5479,17 @@ impl Display for TimeUnit {
 }
 
 /// Enumerates the various types of relative times that can be paired with a [Duration].
+///
+/// The British idioms "[Duration] today" and "[Duration] tomorrow" (e.g. "a week today" or
+/// "a fortnight tomorrow") parse as [TimeDirection::AfterNamed], and are displayed as
+/// "[Duration] after today" / "[Duration] after tomorrow". Likewise, "[Duration] ago today"
+/// and "[Duration] ago tomorrow" (e.g. "a year ago today") parse as
+/// [TimeDirection::BeforeNamed].
+///
+/// Note that [TimeDirection::Ago] and [TimeDirection::FromNow] only ever follow a [Duration],
+/// so "a week ago" is accepted whereas "tomorrow ago" is not.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeDirection {
     /// e.g. `after 18/7/2025 at 3:22 PM`
     AfterAbsolute(AbsoluteTime),
@@ -1327,7 +5553,24 @@ impl Parse for TimeDirection {
                     }
                 }
             }
-            "ago" => Ok(TimeDirection::Ago),
+            "ago" => {
+                // "a year ago today" / "a year ago tomorrow"
+                let named = input.fork().parse::<Ident>().ok().and_then(|ident| {
+                    match ident.to_string().to_lowercase().as_str() {
+                        "today" => Some(NamedRelativeTime::Today),
+                        "tomorrow" => Some(NamedRelativeTime::Tomorrow),
+                        _ => None,
+                    }
+                });
+                let Some(named) = named else {
+                    return Ok(TimeDirection::Ago);
+                };
+                input.parse::<Ident>()?;
+                Ok(TimeDirection::BeforeNamed(named))
+            }
+            // British "a week today" / "a fortnight tomorrow"
+            "today" => Ok(TimeDirection::AfterNamed(NamedRelativeTime::Today)),
+            "tomorrow" => Ok(TimeDirection::AfterNamed(NamedRelativeTime::Tomorrow)),
             "from" => {
                 let ident2 = input.parse::<Ident>()?;
                 if ident2.to_string().to_lowercase().as_str() != "now" {
@@ -1362,6 +5605,7 @@ impl Display for TimeDirection {
 
 /// Represents a positive integer, stored as a [`u64`].
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Number(pub u64);
 
 impl From<u64> for Number {
@@ -1408,6 +5652,118 @@ impl Div for Number {
     }
 }
 
+impl Number {
+    /// Adds `rhs` to this [Number], saturating at [u64::MAX] instead of overflowing.
+    pub fn saturating_add(self, rhs: Number) -> Number {
+        Number(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` from this [Number], saturating at zero instead of underflowing.
+    ///
+    /// ```
+    /// use timelang::*;
+    /// assert_eq!(Number(5).saturating_sub(Number(2)), Number(3));
+    /// assert_eq!(Number(1).saturating_sub(Number(5)), Number(0));
+    /// ```
+    pub fn saturating_sub(self, rhs: Number) -> Number {
+        Number(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Adds `rhs` to this [Number], returning [None] on overflow.
+    pub fn checked_add(self, rhs: Number) -> Option<Number> {
+        self.0.checked_add(rhs.0).map(Number)
+    }
+
+    /// Subtracts `rhs` from this [Number], returning [None] on underflow.
+    pub fn checked_sub(self, rhs: Number) -> Option<Number> {
+        self.0.checked_sub(rhs.0).map(Number)
+    }
+}
+
+impl Number {
+    /// English words for the numbers below twenty.
+    const ONES: [&'static str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+
+    /// The value of a spelled-out number from `one` to `nineteen` (lowercase), if `word` is
+    /// one.
+    fn from_word(word: &str) -> Option<u64> {
+        Number::ONES
+            .iter()
+            .position(|ones| *ones == word)
+            .filter(|n| *n > 0)
+            .map(|n| n as u64)
+    }
+
+    /// Spells out this [Number] in English words, e.g. `one hundred twenty-three`.
+    fn to_words(self) -> String {
+        const TENS: [&str; 10] = [
+            "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+        ];
+        const SCALES: [&str; 7] = [
+            "",
+            "thousand",
+            "million",
+            "billion",
+            "trillion",
+            "quadrillion",
+            "quintillion",
+        ];
+        let below_thousand = |n: u64| {
+            let mut words = Vec::new();
+            if n >= 100 {
+                words.push(format!("{} hundred", Number::ONES[n as usize / 100]));
+            }
+            match n % 100 {
+                0 => (),
+                n @ 1..=19 => words.push(Number::ONES[n as usize].to_string()),
+                n if n % 10 == 0 => words.push(TENS[n as usize / 10].to_string()),
+                n => words.push(format!(
+                    "{}-{}",
+                    TENS[n as usize / 10],
+                    Number::ONES[n as usize % 10]
+                )),
+            }
+            words.join(" ")
+        };
+        if self.0 == 0 {
+            return Number::ONES[0].to_string();
+        }
+        let mut groups = Vec::new();
+        let mut n = self.0;
+        for scale in SCALES {
+            match (n % 1000, scale) {
+                (0, _) => (),
+                (group, "") => groups.push(below_thousand(group)),
+                (group, scale) => groups.push(format!("{} {scale}", below_thousand(group))),
+            }
+            n /= 1000;
+        }
+        groups.reverse();
+        groups.join(" ")
+    }
+}
+
 impl PartialEq<u64> for Number {
     fn eq(&self, other: &u64) -> bool {
         self.0 == *other
@@ -1434,13 +5790,323 @@ impl Display for Number {
     }
 }
 
+/// A [TimeExpression] intended for use as a field type in configuration structs.
+///
+/// [TimeSpec] parses and displays exactly like the [TimeExpression] it wraps, but (with the
+/// `serde` feature enabled) is always (de)serialized as a string, e.g. `"3 days ago"`, rather
+/// than as a structured tree.
+///
+/// ```
+/// use timelang::*;
+/// let spec = "for the next 2 weeks".parse::<TimeSpec>().unwrap();
+/// assert!(matches!(spec.0, TimeExpression::Range(_)));
+/// assert_eq!(spec.to_string(), "from now to 2 weeks from now");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct TimeSpec(pub TimeExpression);
+
+impl Parse for TimeSpec {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(TimeSpec(input.parse()?))
+    }
+}
+
+impl Display for TimeSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<TimeExpression> for TimeSpec {
+    fn from(value: TimeExpression) -> Self {
+        TimeSpec(value)
+    }
+}
+
+impl From<TimeSpec> for TimeExpression {
+    fn from(value: TimeSpec) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TimeSpec {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serde_string::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TimeSpec {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        serde_string::deserialize(deserializer)
+    }
+}
+
+/// Helpers for (de)serializing timelang nodes as their canonical [Display] string rather than
+/// as a structured tree, for use with `#[serde(with = "timelang::serde_string")]`.
+///
+/// Deserialization is performed via the node's [FromStr] impl, so any string that timelang can
+/// parse is accepted, not just the canonical form.
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use timelang::*;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Reminder {
+///     #[serde(with = "timelang::serde_string")]
+///     when: TimeExpression,
+/// }
+///
+/// let reminder: Reminder = serde_json::from_str(r#"{"when":"3 days ago"}"#).unwrap();
+/// assert_eq!(reminder.when.to_string(), "3 days ago");
+/// assert_eq!(
+///     serde_json::to_string(&reminder).unwrap(),
+///     r#"{"when":"3 days ago"}"#
+/// );
+/// ```
+#[cfg(feature = "serde")]
+pub mod serde_string {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    /// Serializes `value` as its [Display] string.
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    /// Deserializes a string and parses it via [FromStr].
+    pub fn deserialize<'de, T, D>(deserializer: D) -> std::result::Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse::<T>()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The error returned by the [FromStr] impls of the timelang AST types.
+///
+/// Unlike [syn::Error], whose spans carry no position information when parsing from a string,
+/// this records the character (not byte) offset into the original input at which parsing
+/// stopped, i.e. the start of the first token that was not consumed, or the length of the
+/// input if all of it was consumed. Depending on whether the parser consumed the offending
+/// token before rejecting it, this is either the start of that token or just past its end.
+///
+/// ```
+/// use timelang::*;
+/// let err = "3 days agoo".parse::<TimeExpression>().unwrap_err();
+/// assert_eq!(err.offset, 7);
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct ParseError {
+    /// The character offset into the input at which parsing failed.
+    pub offset: usize,
+    /// A human-readable description of the failure.
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (at offset {})", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a `T` from `s` using `parser`, converting any failure into a [ParseError] positioned
+/// at the first token that was not consumed by the parser.
+fn parse_str_with_offset<T>(
+    s: &str,
+    parser: impl Fn(ParseStream) -> Result<T>,
+) -> std::result::Result<T, ParseError> {
+    let (normalized, inserted) = normalize_input(s);
+    let mut remaining = None;
+    let result = syn::parse::Parser::parse_str(
+        |input: ParseStream| {
+            let result = parser(input).and_then(|value| {
+                if input.is_empty() {
+                    Ok(value)
+                } else {
+                    Err(input.error("unexpected token"))
+                }
+            });
+            let mut cursor = input.cursor();
+            let mut count = 0;
+            while let Some((_, next)) = cursor.token_tree() {
+                count += 1;
+                cursor = next;
+            }
+            remaining = Some(count);
+            result
+        },
+        &normalized,
+    );
+    result.map_err(|err| {
+        let offset = match remaining {
+            Some(remaining) => {
+                let offsets = token_offsets(&normalized);
+                let offset = offsets
+                    .get(offsets.len().saturating_sub(remaining))
+                    .copied()
+                    .unwrap_or_else(|| normalized.chars().count());
+                // map back onto `s` by discounting any characters inserted before the offset
+                offset - inserted.iter().filter(|&&i| i < offset).count()
+            }
+            // the input could not be tokenized at all
+            None => 0,
+        };
+        ParseError {
+            offset,
+            message: err.to_string(),
+        }
+    })
+}
+
+/// Rewrites string input that the tokenizer would otherwise reject or lump together:
+/// - bare possessive apostrophes (as in "2 days' time") become `'s`, since a lone `'` cannot
+///   be tokenized.
+/// - `AM` / `PM` attached directly to a number (as in "5:30PM") are separated from it by a
+///   space, since `30PM` would otherwise be a single integer literal with a `PM` suffix.
+/// - likewise, a `Z` (UTC) attached directly to a number (as in "14:00Z") is separated from it.
+///
+/// Returns the rewritten string along with the character offsets (within it) of each inserted
+/// character.
+fn normalize_input(s: &str) -> (String, Vec<usize>) {
+    let chars = s.chars().collect::<Vec<_>>();
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '_';
+    let mut normalized = String::with_capacity(s.len());
+    let mut inserted = Vec::new();
+    for (i, c) in chars.iter().enumerate() {
+        normalized.push(*c);
+        let insert = if *c == '\'' {
+            (i > 0 && is_word(&chars[i - 1]) && !chars.get(i + 1).is_some_and(is_word))
+                .then_some('s')
+        } else {
+            let am_pm = chars.get(i + 1..i + 3).is_some_and(|next| {
+                matches!(next[0], 'a' | 'A' | 'p' | 'P') && matches!(next[1], 'm' | 'M')
+            });
+            let zulu = chars
+                .get(i + 1)
+                .is_some_and(|next| matches!(next, 'z' | 'Z'))
+                && !chars.get(i + 2).is_some_and(is_word);
+            let suffix_len = if am_pm { 3 } else { 2 };
+            (c.is_ascii_digit()
+                && (am_pm || zulu)
+                && !chars.get(i + suffix_len).is_some_and(is_word))
+            .then_some(' ')
+        };
+        if let Some(insert) = insert {
+            inserted.push(i + inserted.len() + 1);
+            normalized.push(insert);
+        }
+    }
+    (normalized, inserted)
+}
+
+/// Computes the character offsets at which each top-level token tree in `s` starts, mirroring
+/// the way [syn] tokenizes input (groups count as a single token tree, and each punctuation
+/// character is its own token).
+fn token_offsets(s: &str) -> Vec<usize> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut offsets = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        offsets.push(i);
+        i += 1;
+        if is_word(c) {
+            // identifiers and numeric literals (including suffixes and decimal points)
+            while i < chars.len()
+                && (is_word(chars[i])
+                    || (c.is_ascii_digit()
+                        && chars[i] == '.'
+                        && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())))
+            {
+                i += 1;
+            }
+        } else if c == '"' {
+            while i < chars.len() && chars[i] != '"' {
+                i += if chars[i] == '\\' { 2 } else { 1 };
+            }
+            i += 1;
+        } else if c == '\'' && chars.get(i + 1) == Some(&'\'') {
+            // character literal, e.g. 'a'
+            i += 2;
+        } else if let Some(close) = match c {
+            '(' => Some(')'),
+            '[' => Some(']'),
+            '{' => Some('}'),
+            _ => None,
+        } {
+            let mut depth = 1;
+            while i < chars.len() && depth > 0 {
+                if chars[i] == c {
+                    depth += 1;
+                } else if chars[i] == close {
+                    depth -= 1;
+                }
+                i += 1;
+            }
+        }
+    }
+    offsets
+}
+
+/// Asserts that `value` survives a round trip through its [Display] and [FromStr] impls, i.e.
+/// that rendering it and parsing the result yields an equal value, panicking with the
+/// rendered text otherwise. Every AST node produced by the parser is expected to satisfy
+/// this.
+///
+/// Only available with the `testing` feature, for use in downstream test suites.
+///
+/// ```
+/// # #[cfg(feature = "testing")]
+/// # {
+/// use timelang::*;
+/// assert_roundtrip(&"5 days, 10 hours ago".parse::<TimeExpression>().unwrap());
+/// # }
+/// ```
+#[cfg(any(test, feature = "testing"))]
+pub fn assert_roundtrip<T>(value: &T)
+where
+    T: Display + FromStr + PartialEq + std::fmt::Debug,
+    T::Err: std::fmt::Debug,
+{
+    let rendered = value.to_string();
+    match rendered.parse::<T>() {
+        Ok(parsed) => assert_eq!(
+            &parsed, value,
+            "`{rendered}` re-parsed to a different value"
+        ),
+        Err(err) => panic!("failed to re-parse `{rendered}` (from {value:?}): {err:?}"),
+    }
+}
+
 macro_rules! impl_parse_str {
     ($ident:ident) => {
         impl FromStr for $ident {
-            type Err = syn::Error;
+            type Err = ParseError;
 
             fn from_str(s: &str) -> std::prelude::v1::Result<Self, Self::Err> {
-                syn::parse_str(s)
+                parse_str_with_offset(s, <$ident as Parse>::parse)
             }
         }
     };
@@ -1450,6 +6116,9 @@ impl_parse_str!(TimeExpression);
 impl_parse_str!(TimeDirection);
 impl_parse_str!(TimeUnit);
 impl_parse_str!(TimeRange);
+impl_parse_str!(OpenTimeRange);
+impl_parse_str!(Sequence);
+impl_parse_str!(TimeSpec);
 impl_parse_str!(AmPm);
 impl_parse_str!(DayOfMonth);
 impl_parse_str!(Minute);
@@ -1457,12 +6126,20 @@ impl_parse_str!(Month);
 impl_parse_str!(Hour);
 impl_parse_str!(AbsoluteTime);
 impl_parse_str!(Duration);
+impl_parse_str!(SignedDuration);
 impl_parse_str!(RelativeTime);
 impl_parse_str!(PointInTime);
 impl_parse_str!(Time);
 impl_parse_str!(DateTime);
+impl_parse_str!(Date);
+impl_parse_str!(Year);
 impl_parse_str!(RelativeTimeUnit);
 impl_parse_str!(NamedRelativeTime);
+impl_parse_str!(UtcOffset);
+impl_parse_str!(OffsetDateTime);
+impl_parse_str!(OffsetTime);
+impl_parse_str!(QualifiedTime);
+impl_parse_str!(Recurrence);
 
 #[cfg(test)]
 macro_rules! assert_impl_all {
@@ -1488,9 +6165,18 @@ fn test_traits() {
         PointInTime,
         Time,
         DateTime,
+        Date,
+        Year,
         RelativeTimeUnit,
         NamedRelativeTime,
+        UtcOffset,
+        OffsetDateTime,
+        OffsetTime,
+        QualifiedTime,
+        Recurrence,
         TimeRange,
+        OpenTimeRange,
+        TimeSpec,
         TimeExpression : Copy
         + Clone
         + PartialEq
@@ -1503,4 +6189,16 @@ fn test_traits() {
         + core::hash::Hash
         + FromStr
     );
+    assert_impl_all!(
+        Sequence : Clone
+        + PartialEq
+        + Eq
+        + PartialOrd
+        + Ord
+        + core::fmt::Debug
+        + core::fmt::Display
+        + Parse
+        + core::hash::Hash
+        + FromStr
+    );
 }
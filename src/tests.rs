@@ -1,3 +1,6 @@
+// minutes are written as they appear in the input, e.g. `Minute(01)` for "5:01"
+#![allow(clippy::zero_prefixed_literal)]
+
 use super::*;
 use quote::quote;
 use syn::parse2;
@@ -7,7 +10,14 @@ fn test_parse_minutes() {
     assert_eq!(parse2::<Minute>(quote!(59)).unwrap(), Minute(59));
     assert_eq!(parse2::<Minute>(quote!(0)).unwrap(), Minute(0));
     assert!(parse2::<Minute>(quote!(-1)).is_err());
+    assert!(parse2::<Minute>(quote!(60)).is_err());
+    assert_eq!(
+        parse2::<Minute>(quote!(60)).unwrap_err().to_string(),
+        "minute must be between 0 and 59 (inclusive)"
+    );
     assert!(parse2::<Minute>(quote!(61)).is_err());
+    assert!(parse2::<Time>(quote!(10:60)).is_err());
+    assert!(parse2::<Time>(quote!(10:59)).is_ok());
     assert!(parse2::<Minute>(quote!(259)).is_err());
 }
 
@@ -75,6 +85,33 @@ fn test_parse_date() {
     );
 }
 
+#[test]
+fn test_parse_date_month_names() {
+    let date = Date(Month::April, DayOfMonth(20), Year(2021));
+    assert_eq!("20 April 2021".parse::<Date>().unwrap(), date);
+    assert_eq!("20 apr 2021".parse::<Date>().unwrap(), date);
+    assert_eq!("20/Apr/2021".parse::<Date>().unwrap(), date);
+    assert_eq!("20/4/2021".parse::<Date>().unwrap(), date);
+    assert!("20 4 2021".parse::<Date>().is_err());
+    assert!("20 Aprl 2021".parse::<Date>().is_err());
+    assert_eq!(
+        parse2::<Month>(quote!(SEPTEMBER)).unwrap(),
+        Month::September
+    );
+    assert_eq!(
+        "20 april 2021".parse::<TimeExpression>().unwrap(),
+        TimeExpression::Specific(PointInTime::Absolute(AbsoluteTime::Date(date)))
+    );
+    assert_eq!(
+        "3 days after 20 Apr 2021 at 5:00 PM"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "3 days after 20/4/2021 at 5:00 PM"
+    );
+    assert!("20 days".parse::<TimeExpression>().is_ok());
+}
+
 #[test]
 fn test_parse_time() {
     use AmPm::*;
@@ -511,3 +548,3704 @@ fn test_parse_time_expressions() {
         "3 days before yesterday"
     );
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    let expr = parse2::<TimeExpression>(quote!(5 days, 10 hours, and 35 minutes from now)).unwrap();
+    let json = serde_json::to_string(&expr).unwrap();
+    assert_eq!(serde_json::from_str::<TimeExpression>(&json).unwrap(), expr);
+
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Config {
+        #[serde(with = "crate::serde_string")]
+        when: TimeExpression,
+    }
+
+    let config = Config { when: expr };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"when":"5 days, 10 hours, 35 minutes from now"}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    assert!(serde_json::from_str::<Config>(r#"{"when":"5 dayz from now"}"#).is_err());
+}
+
+#[test]
+fn test_parse_for_the_next_past() {
+    let duration = Duration {
+        years: 0.into(),
+        months: 0.into(),
+        weeks: 2.into(),
+        days: 3.into(),
+        hours: 0.into(),
+        minutes: 0.into(),
+    };
+    let now = PointInTime::Relative(RelativeTime::Named(NamedRelativeTime::Now));
+    assert_eq!(
+        parse2::<TimeRange>(quote!(for the next 2 weeks and 3 days)).unwrap(),
+        TimeRange(
+            now,
+            PointInTime::Relative(RelativeTime::Directional {
+                duration,
+                dir: TimeDirection::FromNow
+            })
+        )
+    );
+    assert_eq!(
+        parse2::<TimeExpression>(quote!(for the past 2 weeks, 3 days)).unwrap(),
+        TimeExpression::Range(TimeRange(
+            PointInTime::Relative(RelativeTime::Directional {
+                duration,
+                dir: TimeDirection::Ago
+            }),
+            now
+        ))
+    );
+    assert_eq!(
+        parse2::<TimeExpression>(quote!(for the past 2 weeks and 3 days))
+            .unwrap()
+            .to_string(),
+        "from 2 weeks, 3 days ago to now"
+    );
+    assert_eq!(
+        parse2::<TimeExpression>(quote!(for the next 2 weeks and 3 days))
+            .unwrap()
+            .to_string(),
+        "from now to 2 weeks, 3 days from now"
+    );
+    assert_eq!(
+        "from now to 2 weeks, 3 days from now"
+            .parse::<TimeRange>()
+            .unwrap(),
+        "for the next 2 weeks and 3 days"
+            .parse::<TimeRange>()
+            .unwrap()
+    );
+    assert_eq!(
+        parse2::<TimeRange>(quote!(for the upcoming 2 weeks))
+            .unwrap_err()
+            .to_string(),
+        "expected `next`, `past` or `last`"
+    );
+}
+
+#[test]
+fn test_resolve_relative_time() {
+    let now = parse2::<DateTime>(quote!(15/3/2024 at 10:30)).unwrap(); // a Friday
+    let resolve = |tokens| parse2::<RelativeTime>(tokens).unwrap().resolve(now);
+    assert_eq!(
+        resolve(quote!(2 days and 14 hours after the day after tomorrow)),
+        parse2::<DateTime>(quote!(19/3/2024 14:00)).unwrap()
+    );
+    assert_eq!(resolve(quote!(now)), now);
+    assert_eq!(
+        resolve(quote!(tomorrow)),
+        parse2::<DateTime>(quote!(16/3/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(day before yesterday)),
+        parse2::<DateTime>(quote!(13/3/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(3 days, 11 hours ago)),
+        parse2::<DateTime>(quote!(11/3/2024 23:30)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(17 days from now)),
+        parse2::<DateTime>(quote!(1/4/2024 10:30)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(next tuesday)),
+        parse2::<DateTime>(quote!(19/3/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(next friday)),
+        parse2::<DateTime>(quote!(22/3/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(last friday)),
+        parse2::<DateTime>(quote!(8/3/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(next week)),
+        parse2::<DateTime>(quote!(18/3/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(last month)),
+        parse2::<DateTime>(quote!(1/2/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(next year)),
+        parse2::<DateTime>(quote!(1/1/2025 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(1 month after 31/1/2024)),
+        parse2::<DateTime>(quote!(29/2/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(1 year, 2 hours before 29/2/2024 at 12:15 AM)),
+        parse2::<DateTime>(quote!(27/2/2023 22:15)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(2 weeks before last sunday)),
+        parse2::<DateTime>(quote!(25/2/2024 0:00)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(65535 years from now)),
+        parse2::<DateTime>(quote!(31/12/65535 23:59)).unwrap()
+    );
+    assert_eq!(
+        parse2::<PointInTime>(quote!(1 / 1 / 2020))
+            .unwrap()
+            .resolve(now),
+        parse2::<DateTime>(quote!(1/1/2020 0:00)).unwrap()
+    );
+}
+
+#[test]
+fn test_temporal_position() {
+    use TemporalPosition::*;
+
+    let now = parse2::<DateTime>(quote!(15/3/2024 at 10:30)).unwrap();
+    let position = |tokens| {
+        parse2::<TimeExpression>(tokens)
+            .unwrap()
+            .temporal_position(now)
+    };
+    assert_eq!(position(quote!(22 / 4 / 1991)), Some(Past));
+    assert_eq!(position(quote!(3 days from now)), Some(Future));
+    assert_eq!(position(quote!(now)), Some(Present));
+    assert_eq!(
+        position(quote!(from 1/3/2024 to next tuesday)),
+        Some(Present)
+    );
+    assert_eq!(
+        position(quote!(from next tuesday to 1/3/2024)),
+        Some(Present)
+    );
+    assert_eq!(position(quote!(from 1/3/2024 to yesterday)), Some(Past));
+    assert_eq!(position(quote!(for the next 3 hours)), Some(Present));
+    assert_eq!(position(quote!(from tomorrow to next year)), Some(Future));
+    assert_eq!(position(quote!(3 days)), None);
+    assert!(parse2::<TimeExpression>(quote!(15/3/2024 at 10:29))
+        .unwrap()
+        .is_in_past(now));
+    assert!(parse2::<TimeExpression>(quote!(15/3/2024 at 10:31))
+        .unwrap()
+        .is_in_future(now));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_interop() {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    let date_time: NaiveDateTime = parse2::<DateTime>(quote!(15/6/2022 at 14:00))
+        .unwrap()
+        .try_into()
+        .unwrap();
+    assert_eq!(
+        date_time,
+        NaiveDate::from_ymd_opt(2022, 6, 15)
+            .unwrap()
+            .and_hms_opt(14, 0, 0)
+            .unwrap()
+    );
+    assert_eq!(
+        DateTime::try_from(date_time).unwrap(),
+        parse2::<DateTime>(quote!(15/6/2022 at 14:00)).unwrap()
+    );
+    assert_eq!(
+        NaiveTime::try_from(parse2::<Time>(quote!(12:30 AM)).unwrap()).unwrap(),
+        NaiveTime::from_hms_opt(0, 30, 0).unwrap()
+    );
+    assert_eq!(
+        NaiveTime::try_from(parse2::<Time>(quote!(12:30 PM)).unwrap()).unwrap(),
+        NaiveTime::from_hms_opt(12, 30, 0).unwrap()
+    );
+    assert_eq!(
+        NaiveTime::try_from(parse2::<Time>(quote!(11:59 PM)).unwrap()).unwrap(),
+        NaiveTime::from_hms_opt(23, 59, 0).unwrap()
+    );
+    assert_eq!(
+        Time::from(NaiveTime::from_hms_opt(7, 5, 33).unwrap()),
+        Time(Hour::Hour24(7), Minute(5))
+    );
+    assert_eq!(
+        Date::try_from(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()).unwrap(),
+        parse2::<Date>(quote!(29 / 2 / 2024)).unwrap()
+    );
+    assert!(NaiveDate::try_from(parse2::<Date>(quote!(31 / 2 / 2024)).unwrap()).is_err());
+    assert!(NaiveDate::try_from(parse2::<Date>(quote!(29 / 2 / 2023)).unwrap()).is_err());
+    assert!(Date::try_from(NaiveDate::from_ymd_opt(-5, 1, 1).unwrap()).is_err());
+    assert_eq!(
+        parse2::<Duration>(quote!(1 week, 2 days, 3 hours))
+            .unwrap()
+            .to_chrono(),
+        chrono::Duration::hours(9 * 24 + 3)
+    );
+    assert_eq!(
+        parse2::<Duration>(quote!(1 year, 1 month))
+            .unwrap()
+            .to_chrono(),
+        chrono::Duration::days(395)
+    );
+    assert_eq!(
+        Duration {
+            years: u64::MAX.into(),
+            months: 0.into(),
+            weeks: 0.into(),
+            days: 0.into(),
+            hours: 0.into(),
+            minutes: 0.into(),
+        }
+        .to_chrono(),
+        chrono::Duration::MAX
+    );
+}
+
+#[test]
+fn test_parse_now_with_offset() {
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now +09:00)).unwrap(),
+        RelativeTime::Named(NamedRelativeTime::NowIn(UtcOffset(9 * 60)))
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now UTC)).unwrap(),
+        RelativeTime::Named(NamedRelativeTime::NowIn(UtcOffset(0)))
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now -03:30)).unwrap(),
+        RelativeTime::Named(NamedRelativeTime::NowIn(UtcOffset(-210)))
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now PST)).unwrap(),
+        RelativeTime::Named(NamedRelativeTime::NowIn(UtcOffset(-8 * 60)))
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now +09:00))
+            .unwrap()
+            .to_string(),
+        "now +09:00"
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now UTC)).unwrap().to_string(),
+        "now +00:00"
+    );
+    assert_eq!(
+        parse2::<TimeRange>(quote!(from now UTC to tomorrow)).unwrap(),
+        TimeRange(
+            PointInTime::Relative(RelativeTime::Named(NamedRelativeTime::NowIn(UtcOffset(0)))),
+            PointInTime::Relative(RelativeTime::Named(NamedRelativeTime::Tomorrow))
+        )
+    );
+    assert_eq!(
+        parse2::<TimeRange>(quote!(from now to tomorrow)).unwrap().0,
+        PointInTime::Relative(RelativeTime::Named(NamedRelativeTime::Now))
+    );
+    assert!(parse2::<RelativeTime>(quote!(now +15:00)).is_err());
+    assert!(parse2::<RelativeTime>(quote!(now Tokyo)).is_err());
+
+    let now = parse2::<DateTime>(quote!(15/3/2024 at 20:30)).unwrap();
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now +09:00))
+            .unwrap()
+            .resolve(now),
+        parse2::<DateTime>(quote!(16/3/2024 at 5:30)).unwrap()
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(now UTC))
+            .unwrap()
+            .resolve(now),
+        parse2::<DateTime>(quote!(15/3/2024 at 20:30)).unwrap()
+    );
+}
+
+#[test]
+fn test_duration_fractional() {
+    let duration = |tokens| parse2::<Duration>(tokens).unwrap();
+    assert_eq!(duration(quote!(90 minutes)).as_fractional_hours(), 1.5);
+    assert_eq!(duration(quote!(36 hours)).as_fractional_days(), 1.5);
+    assert_eq!(duration(quote!(1 week, 12 hours)).as_fractional_days(), 7.5);
+    assert_eq!(duration(quote!(1 month)).as_fractional_days(), 30.0);
+    assert_eq!(duration(quote!(1 year)).as_fractional_hours(), 365.0 * 24.0);
+}
+
+#[test]
+fn test_hour_conversion() {
+    use AmPm::*;
+
+    assert_eq!(Hour::Hour12(12, AM).to_hour24(), 0);
+    assert_eq!(Hour::Hour12(12, PM).to_hour24(), 12);
+    assert_eq!(Hour::Hour12(1, AM).to_hour24(), 1);
+    assert_eq!(Hour::Hour12(11, PM).to_hour24(), 23);
+    assert_eq!(Hour::Hour12(1, PM).to_hour24(), 13);
+    assert_eq!(Hour::Hour24(17).to_hour24(), 17);
+
+    assert_eq!(Hour::Hour24(0).to_hour12(), (12, AM));
+    assert_eq!(Hour::Hour24(12).to_hour12(), (12, PM));
+    assert_eq!(Hour::Hour24(1).to_hour12(), (1, AM));
+    assert_eq!(Hour::Hour24(23).to_hour12(), (11, PM));
+    assert_eq!(Hour::Hour24(13).to_hour12(), (1, PM));
+    assert_eq!(Hour::Hour12(12, AM).to_hour12(), (12, AM));
+}
+
+#[test]
+fn test_parse_lenient() {
+    assert_eq!(
+        TimeExpression::parse_lenient("  tomorrow  ").unwrap(),
+        TimeExpression::Specific(PointInTime::Relative(RelativeTime::Named(
+            NamedRelativeTime::Tomorrow
+        )))
+    );
+    assert_eq!(
+        TimeExpression::parse_lenient("3 days ago.").unwrap(),
+        parse2::<TimeExpression>(quote!(3 days ago)).unwrap()
+    );
+    assert_eq!(
+        TimeExpression::parse_lenient("next   tuesday").unwrap(),
+        parse2::<TimeExpression>(quote!(next tuesday)).unwrap()
+    );
+    assert_eq!(
+        TimeExpression::parse_lenient("\tfrom 1/1/2023\n to  15/1/2023 !?").unwrap(),
+        parse2::<TimeExpression>(quote!(from 1/1/2023 to 15/1/2023)).unwrap()
+    );
+    assert!("3 days ago.".parse::<TimeExpression>().is_err());
+    assert!(TimeExpression::parse_lenient("3 dayz ago.").is_err());
+    assert!(TimeExpression::parse_lenient("3 days. ago").is_err());
+    assert!(TimeExpression::parse_lenient(" . ").is_err());
+    // offsets refer to the original input, despite the trimming
+    let err = TimeExpression::parse_lenient("\t3 days   agoo!").unwrap_err();
+    assert_eq!(err.offset, "\t3 days   ".len());
+    assert_eq!(
+        err,
+        "\t3 days   agoo".parse::<TimeExpression>().unwrap_err()
+    );
+}
+
+#[test]
+fn test_parse_this_time() {
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(this time tomorrow)).unwrap(),
+        RelativeTime::ThisTimeNamed(NamedRelativeTime::Tomorrow)
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(this time next week)).unwrap(),
+        RelativeTime::ThisTimeNext(RelativeTimeUnit::Week)
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(This Time Last Friday)).unwrap(),
+        RelativeTime::ThisTimeLast(RelativeTimeUnit::Friday)
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(this time the day after tomorrow))
+            .unwrap()
+            .to_string(),
+        "this time the day after tomorrow"
+    );
+    assert_eq!(
+        parse2::<RelativeTime>(quote!(this time next week))
+            .unwrap()
+            .to_string(),
+        "this time next week"
+    );
+    assert!(parse2::<RelativeTime>(quote!(this tomorrow)).is_err());
+
+    let now = parse2::<DateTime>(quote!(31/1/2024 at 4:45 PM)).unwrap(); // a Wednesday
+    let resolve = |tokens| parse2::<RelativeTime>(tokens).unwrap().resolve(now);
+    assert_eq!(
+        resolve(quote!(this time tomorrow)),
+        parse2::<DateTime>(quote!(1/2/2024 at 4:45 PM)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(this time next week)),
+        parse2::<DateTime>(quote!(7/2/2024 at 4:45 PM)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(this time next month)),
+        parse2::<DateTime>(quote!(29/2/2024 at 4:45 PM)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(this time last year)),
+        parse2::<DateTime>(quote!(31/1/2023 at 4:45 PM)).unwrap()
+    );
+    assert_eq!(
+        resolve(quote!(this time next monday)),
+        parse2::<DateTime>(quote!(5/2/2024 at 4:45 PM)).unwrap()
+    );
+}
+
+#[test]
+fn test_parse_duration_overflow() {
+    let err = parse2::<Duration>(quote!(999999999999999 years)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "duration must not exceed 34445721600 minutes"
+    );
+    // the default bound is the span of the range representable by `Year`
+    assert!(parse2::<Duration>(quote!(999999999 years)).is_err());
+    assert!(parse2::<Duration>(quote!(65536 years)).is_ok());
+    assert!(parse2::<Duration>(quote!(65536 years and 1 minute)).is_err());
+    assert!(parse2::<Duration>(quote!(34445721600 minutes)).is_ok());
+    assert!(parse2::<TimeExpression>(quote!(999999999 years ago)).is_err());
+    // even without a bound, durations must fit in a `u64` number of minutes
+    let unbounded = |s| Duration::parse_with_max_minutes(s, u64::MAX);
+    assert!(unbounded("18446744073709551615 minutes").is_ok());
+    assert!(unbounded("18446744073709551615 minutes and 1 minute").is_err());
+    assert!(unbounded("18446744073709551615 minutes, 1 hour").is_err());
+    assert!(unbounded("18446744073709551616 minutes").is_err());
+    assert!(unbounded("999999999999999 years").is_err());
+    assert_eq!(
+        Duration::parse_with_max_minutes("2 hours", 120).unwrap(),
+        parse2::<Duration>(quote!(2 hours)).unwrap()
+    );
+    assert_eq!(
+        Duration::parse_with_max_minutes("2 hours and 1 minute", 120)
+            .unwrap_err()
+            .message,
+        "duration must not exceed 120 minutes"
+    );
+    assert_eq!(
+        parse2::<Year>(quote!(70000)).unwrap_err().to_string(),
+        "year must be between 0 and 65535 (inclusive)"
+    );
+    assert!(parse2::<Date>(quote!(1 / 1 / 65536)).is_err());
+    assert_eq!(parse2::<Year>(quote!(65535)).unwrap(), Year(65535));
+}
+
+#[test]
+fn test_duration_total_minutes() {
+    assert_eq!("1 hour".parse::<Duration>().unwrap().total_minutes(), 60);
+    assert_eq!("1 week".parse::<Duration>().unwrap().total_minutes(), 10080);
+    assert_eq!(
+        "1 year, 1 month and 1 day"
+            .parse::<Duration>()
+            .unwrap()
+            .total_minutes(),
+        (365 + 30 + 1) * 1440
+    );
+    let short = "2 minutes".parse::<Duration>().unwrap();
+    let long = "1 hour".parse::<Duration>().unwrap();
+    assert!(short > long);
+    assert!(short.total_minutes() < long.total_minutes());
+    assert_eq!(
+        Duration {
+            minutes: Number(0),
+            hours: Number(0),
+            days: Number(0),
+            weeks: Number(0),
+            months: Number(0),
+            years: Number(u64::MAX),
+        }
+        .total_minutes(),
+        u64::MAX
+    );
+}
+
+#[test]
+fn test_parse_open_time_range() {
+    let start = PointInTime::Absolute(AbsoluteTime::Date(Date(
+        Month::January,
+        DayOfMonth(1),
+        Year(2024),
+    )));
+    assert_eq!(
+        "from 1/1/2024".parse::<TimeExpression>().unwrap(),
+        TimeExpression::OpenRange(OpenTimeRange::From(start))
+    );
+    assert_eq!(
+        "to 1/1/2024".parse::<TimeExpression>().unwrap(),
+        TimeExpression::OpenRange(OpenTimeRange::To(start))
+    );
+    assert!(matches!(
+        "from 1/1/2024 to 1/1/2025"
+            .parse::<TimeExpression>()
+            .unwrap(),
+        TimeExpression::Range(_)
+    ));
+    assert!("from 1/1/2024 to".parse::<TimeExpression>().is_err());
+    for s in ["from 1/1/2024 at 10:00", "to tomorrow", "from 3 days ago"] {
+        assert_eq!(s.parse::<TimeExpression>().unwrap().to_string(), s);
+        assert_eq!(s.parse::<OpenTimeRange>().unwrap().to_string(), s);
+    }
+
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let from = "from 1/1/2024".parse::<OpenTimeRange>().unwrap();
+    let to = "to tomorrow".parse::<OpenTimeRange>().unwrap();
+    let early = "1/1/1900".parse::<AbsoluteTime>().unwrap();
+    let boundary = "1/1/2024 at 0:00".parse::<AbsoluteTime>().unwrap();
+    let late = "1/1/3000".parse::<AbsoluteTime>().unwrap();
+    assert!(!from.contains(&early, now));
+    assert!(from.contains(&boundary, now));
+    assert!(from.contains(&late, now));
+    assert!(to.contains(&early, now));
+    assert!(to.contains(&"16/3/2024".parse().unwrap(), now));
+    assert!(!to.contains(&late, now));
+    assert_eq!(
+        "from 1/1/2024"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .temporal_position(now),
+        Some(TemporalPosition::Present)
+    );
+}
+
+#[test]
+fn test_time_range_same_interval() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let range = "from 1/1/2023 to 15/1/2023".parse::<TimeRange>().unwrap();
+    let reversed = "from 15/1/2023 to 1/1/2023".parse::<TimeRange>().unwrap();
+    assert_ne!(range, reversed);
+    assert!(range.same_interval(&reversed, now));
+    assert!(range.same_interval(&range, now));
+    let relative = "from now to yesterday".parse::<TimeRange>().unwrap();
+    let absolute = "from 14/3/2024 to 15/3/2024 at 10:30"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert!(relative.same_interval(&absolute, now));
+    let other = "from 1/1/2023 to 16/1/2023".parse::<TimeRange>().unwrap();
+    assert!(!range.same_interval(&other, now));
+}
+
+#[test]
+fn test_duration_add_sub() {
+    let hour = "1 hour".parse::<Duration>().unwrap();
+    let ninety = "90 minutes".parse::<Duration>().unwrap();
+    assert_eq!((hour + ninety).to_string(), "1 hour, 90 minutes");
+    assert_eq!(
+        ("2 days".parse::<Duration>().unwrap() + "3 days and 1 week".parse().unwrap()).to_string(),
+        "1 week, 5 days"
+    );
+    assert_eq!((hour + ninety - hour).to_string(), "90 minutes");
+    let diff = "30 minutes".parse::<Duration>().unwrap() - ninety;
+    assert_eq!(diff.minutes, Number(0));
+    assert_eq!(diff.total_minutes(), 0);
+    assert_eq!((hour - ninety).to_string(), "1 hour");
+}
+
+#[test]
+fn test_duration_normalize() {
+    let normalize = |s: &str| s.parse::<Duration>().unwrap().normalize().to_string();
+    assert_eq!(normalize("90 minutes"), "1 hour, 30 minutes");
+    assert_eq!(normalize("36 hours"), "1 day, 12 hours");
+    assert_eq!(normalize("1500 minutes"), "1 day, 1 hour");
+    assert_eq!(normalize("10 days"), "1 week, 3 days");
+    assert_eq!(normalize("5 weeks"), "5 weeks");
+    assert_eq!(normalize("14 months"), "1 year, 2 months");
+    assert_eq!(normalize("1 hour, 30 minutes"), "1 hour, 30 minutes");
+    let weeks = "5 weeks and 13 months".parse::<Duration>().unwrap();
+    assert_eq!(
+        weeks.normalize_with(true).to_string(),
+        "1 year, 2 months, 5 days"
+    );
+    assert_eq!(
+        "90 minutes"
+            .parse::<Duration>()
+            .unwrap()
+            .normalize()
+            .total_minutes(),
+        90
+    );
+}
+
+#[test]
+fn test_parse_hyphenated_named_relative_times() {
+    use NamedRelativeTime::*;
+    for (s, named, display) in [
+        ("mid-day", Midday, "mid day"),
+        ("mid day", Midday, "mid day"),
+        ("midday", Midday, "mid day"),
+        ("end-of-day", EndOfDay, "end of day"),
+        ("end of day", EndOfDay, "end of day"),
+        (
+            "day-after-tomorrow",
+            DayAfterTomorrow,
+            "the day after tomorrow",
+        ),
+        (
+            "the day-before-yesterday",
+            DayBeforeYesterday,
+            "the day before yesterday",
+        ),
+    ] {
+        let parsed = s.parse::<NamedRelativeTime>().unwrap();
+        assert_eq!(parsed, named);
+        assert_eq!(parsed.to_string(), display);
+        assert_eq!(display.parse::<NamedRelativeTime>().unwrap(), named);
+    }
+    assert!("end-of-decade".parse::<NamedRelativeTime>().is_err());
+    assert!("mid-week".parse::<NamedRelativeTime>().is_err());
+    assert_eq!(
+        "2 hours before end-of-day"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "2 hours before end of day"
+    );
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    assert_eq!(
+        Midday.resolve(now),
+        "15/3/2024 at 12:00".parse::<DateTime>().unwrap()
+    );
+    assert_eq!(
+        EndOfDay.resolve(now),
+        "15/3/2024 at 23:59".parse::<DateTime>().unwrap()
+    );
+}
+
+#[test]
+fn test_time_display_12h_24h() {
+    for (s, twelve, twenty_four) in [
+        ("14:30", "2:30 PM", "14:30"),
+        ("2:30 PM", "2:30 PM", "14:30"),
+        ("0:00", "12:00 AM", "0:00"),
+        ("12:00 AM", "12:00 AM", "0:00"),
+        ("12:05", "12:05 PM", "12:05"),
+        ("12:05 PM", "12:05 PM", "12:05"),
+        ("9:07 AM", "9:07 AM", "9:07"),
+    ] {
+        let time = s.parse::<Time>().unwrap();
+        assert_eq!(time.display_12h().to_string(), twelve);
+        assert_eq!(time.display_24h().to_string(), twenty_four);
+    }
+}
+
+#[test]
+fn test_parse_in_duration() {
+    for (s, canonical) in [
+        ("in 3 days", "3 days from now"),
+        ("in 2 hours and 15 minutes", "2 hours, 15 minutes from now"),
+    ] {
+        let expected = canonical.parse::<RelativeTime>().unwrap();
+        assert_eq!(s.parse::<RelativeTime>().unwrap(), expected);
+        assert_eq!(
+            s.parse::<TimeExpression>().unwrap(),
+            TimeExpression::Specific(PointInTime::Relative(expected))
+        );
+        assert_eq!(s.parse::<TimeExpression>().unwrap().to_string(), canonical);
+    }
+    assert!(matches!(
+        "in 3 days".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Directional {
+            dir: TimeDirection::FromNow,
+            ..
+        }
+    ));
+    assert!("from now to in 2 weeks".parse::<TimeRange>().is_ok());
+    assert!("in tomorrow".parse::<RelativeTime>().is_err());
+    assert!("in 3 days ago".parse::<TimeExpression>().is_err());
+}
+
+#[test]
+fn test_parse_sequence() {
+    let now = "15/3/2024 at 10:00".parse::<DateTime>().unwrap();
+    let sequence = "2 hours from now and then 30 minutes later"
+        .parse::<Sequence>()
+        .unwrap();
+    assert_eq!(sequence.0.len(), 2);
+    assert_eq!(
+        sequence.to_string(),
+        "2 hours from now and then 30 minutes later"
+    );
+    assert_eq!(
+        sequence.resolve(now),
+        vec![
+            "15/3/2024 at 12:00".parse::<DateTime>().unwrap(),
+            "15/3/2024 at 12:30".parse::<DateTime>().unwrap(),
+        ]
+    );
+    let sequence = "in 1 day and then 2 hours ago and then tomorrow"
+        .parse::<Sequence>()
+        .unwrap();
+    assert_eq!(
+        sequence.to_string(),
+        "1 day from now and then 2 hours ago and then tomorrow"
+    );
+    assert_eq!(
+        sequence.resolve(now),
+        vec![
+            "16/3/2024 at 10:00".parse::<DateTime>().unwrap(),
+            "16/3/2024 at 8:00".parse::<DateTime>().unwrap(),
+            "17/3/2024 at 0:00".parse::<DateTime>().unwrap(),
+        ]
+    );
+    assert_eq!(sequence.to_string().parse::<Sequence>().unwrap(), sequence);
+    assert!("2 hours from now and then".parse::<Sequence>().is_err());
+    assert!("30 minutes later".parse::<Sequence>().is_err());
+}
+
+#[test]
+fn test_parse_open_time_range_keywords() {
+    let date = |s: &str| PointInTime::Absolute(s.parse().unwrap());
+    assert_eq!(
+        "from 1/1/2020".parse::<TimeExpression>().unwrap(),
+        TimeExpression::OpenRange(OpenTimeRange::From(date("1/1/2020")))
+    );
+    assert_eq!(
+        "after 1/1/2020".parse::<TimeExpression>().unwrap(),
+        TimeExpression::OpenRange(OpenTimeRange::From(date("1/1/2020")))
+    );
+    assert_eq!(
+        "until 15/1/2023".parse::<TimeExpression>().unwrap(),
+        TimeExpression::OpenRange(OpenTimeRange::To(date("15/1/2023")))
+    );
+    assert_eq!(
+        "before tomorrow".parse::<OpenTimeRange>().unwrap(),
+        OpenTimeRange::To(PointInTime::Relative(RelativeTime::Named(
+            NamedRelativeTime::Tomorrow
+        )))
+    );
+    for s in ["after 1/1/2020", "until 15/1/2023", "before 3 days ago"] {
+        let parsed = s.parse::<TimeExpression>().unwrap();
+        assert_eq!(
+            parsed.to_string().parse::<TimeExpression>().unwrap(),
+            parsed
+        );
+    }
+    assert_eq!(
+        "until 15/1/2023"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "to 15/1/2023"
+    );
+    // a leading duration still makes this a point in time
+    assert!(matches!(
+        "3 days after 1/1/2020".parse::<TimeExpression>().unwrap(),
+        TimeExpression::Specific(_)
+    ));
+    assert!("since 1/1/2020".parse::<OpenTimeRange>().is_err());
+}
+
+#[test]
+fn test_time_range_contains() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let range = "from 1/1/2023 to 15/1/2023".parse::<TimeRange>().unwrap();
+    let point = |s: &str| s.parse::<AbsoluteTime>().unwrap();
+    assert!(range.contains(&point("10/1/2023 at 3:15 PM"), now));
+    assert!(range.contains(&point("1/1/2023"), now));
+    assert!(range.contains(&point("1/1/2023 at 0:00"), now));
+    assert!(range.contains(&point("15/1/2023"), now));
+    assert!(!range.contains(&point("15/1/2023 at 0:01"), now));
+    assert!(!range.contains(&point("31/12/2022 at 23:59"), now));
+    let reversed = "from 15/1/2023 to 1/1/2023".parse::<TimeRange>().unwrap();
+    assert!(reversed.contains(&point("10/1/2023"), now));
+    let relative = "from yesterday to 2 hours from now"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert!(relative.contains(&point("15/3/2024 at 12:30"), now));
+    assert!(!relative.contains(&point("15/3/2024 at 12:31"), now));
+    assert!(relative.contains(&point("14/3/2024"), now));
+    assert!(!relative.contains(&point("13/3/2024 at 23:59"), now));
+}
+
+#[test]
+fn test_checked_add_months() {
+    use MonthEndPolicy::*;
+    let date = |s: &str| s.parse::<Date>().unwrap();
+    let jan31 = date("31/1/2023");
+    assert_eq!(jan31.checked_add_months(1, Clamp), Some(date("28/2/2023")));
+    assert_eq!(jan31.checked_add_months(1, Reject), None);
+    assert_eq!(
+        jan31.checked_add_months(1, Overflow),
+        Some(date("3/3/2023"))
+    );
+    let leap = date("31/1/2024");
+    assert_eq!(leap.checked_add_months(1, Clamp), Some(date("29/2/2024")));
+    assert_eq!(leap.checked_add_months(1, Overflow), Some(date("2/3/2024")));
+    for policy in [Clamp, Reject, Overflow] {
+        assert_eq!(jan31.checked_add_months(2, policy), Some(date("31/3/2023")));
+        assert_eq!(
+            jan31.checked_add_months(-1, policy),
+            Some(date("31/12/2022"))
+        );
+        assert_eq!(jan31.checked_add_months(0, policy), Some(jan31));
+        assert_eq!(date("15/12/65535").checked_add_months(1, policy), None);
+        assert_eq!(date("15/1/0").checked_add_months(-1, policy), None);
+    }
+    assert_eq!(
+        jan31.checked_add_months(-3, Clamp),
+        Some(date("31/10/2022"))
+    );
+    assert_eq!(
+        date("31/3/2023").checked_add_months(-1, Clamp),
+        Some(date("28/2/2023"))
+    );
+    assert_eq!(
+        "31/1/2024 at 10:15"
+            .parse::<DateTime>()
+            .unwrap()
+            .checked_add_months(1, Overflow),
+        Some("2/3/2024 at 10:15".parse().unwrap())
+    );
+    assert_eq!(
+        "31/1/2024 at 10:15".parse::<DateTime>().unwrap()
+            + "1 month and 2 hours".parse::<Duration>().unwrap(),
+        "29/2/2024 at 12:15".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_time_range_new() {
+    let point = |s: &str| s.parse::<PointInTime>().unwrap();
+    assert_eq!(
+        TimeRange::new(point("1/1/2023"), point("15/1/2023")),
+        Ok(TimeRange(point("1/1/2023"), point("15/1/2023")))
+    );
+    assert!(TimeRange::new(point("1/1/2023"), point("1/1/2023 at 0:00")).is_ok());
+    let err = TimeRange::new(point("15/1/2023"), point("1/1/2023 at 11:00 PM")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "range end 1/1/2023 at 11:00 PM precedes range start 15/1/2023"
+    );
+    assert!(TimeRange::new(point("1/1/2023 at 10:01"), point("1/1/2023 at 10:00")).is_err());
+    // relative endpoints are not checked
+    assert!(TimeRange::new(point("tomorrow"), point("yesterday")).is_ok());
+    assert!(TimeRange::new(point("15/1/2023"), point("3 days ago")).is_ok());
+    // parsing remains tolerant
+    assert!("from 15/1/2023 to 1/1/2023".parse::<TimeRange>().is_ok());
+}
+
+#[test]
+fn test_parse_named_at_keyword_time() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let expected = RelativeTime::NamedAt(
+        NamedRelativeTime::Tomorrow,
+        Time(Hour::Hour24(12), Minute(0)).into(),
+    );
+    for s in [
+        "tomorrow at noon",
+        "noon tomorrow",
+        "at noon tomorrow",
+        "tomorrow at 12:00",
+    ] {
+        let parsed = s.parse::<RelativeTime>().unwrap();
+        assert_eq!(parsed, expected);
+        assert_eq!(parsed.to_string(), "tomorrow at 12:00");
+        assert_eq!(
+            parsed.resolve(now),
+            "16/3/2024 at 12:00".parse::<DateTime>().unwrap()
+        );
+        assert_eq!(
+            s.parse::<TimeExpression>().unwrap(),
+            TimeExpression::Specific(PointInTime::Relative(expected))
+        );
+    }
+    let midnight = "midnight the day after tomorrow"
+        .parse::<RelativeTime>()
+        .unwrap();
+    assert_eq!(
+        midnight,
+        "the day after tomorrow at midnight".parse().unwrap()
+    );
+    assert_eq!(
+        midnight.resolve(now),
+        "17/3/2024 at 0:00".parse::<DateTime>().unwrap()
+    );
+    assert!("tomorrow at teatime".parse::<RelativeTime>().is_err());
+    assert!("noon".parse::<RelativeTime>().is_err());
+}
+
+#[test]
+fn test_time_spec() {
+    let spec = "in 3 days".parse::<TimeSpec>().unwrap();
+    assert_eq!(spec.0, "3 days from now".parse::<TimeExpression>().unwrap());
+    assert_eq!(spec.to_string(), "3 days from now");
+    assert_eq!(TimeExpression::from(spec), spec.0);
+    assert!("3 dayz".parse::<TimeSpec>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_time_spec_serde() {
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Config {
+        retention: TimeSpec,
+        window: Option<TimeSpec>,
+    }
+
+    let config: Config =
+        serde_json::from_str(r#"{"retention":"2 weeks","window":"from 1/1/2024 to tomorrow"}"#)
+            .unwrap();
+    assert_eq!(
+        config.retention,
+        TimeSpec(TimeExpression::Duration("2 weeks".parse().unwrap()))
+    );
+    assert!(matches!(
+        config.window,
+        Some(TimeSpec(TimeExpression::Range(_)))
+    ));
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"retention":"2 weeks","window":"from 1/1/2024 to tomorrow"}"#
+    );
+    assert!(serde_json::from_str::<Config>(r#"{"retention":"2 weekz","window":null}"#).is_err());
+}
+
+#[test]
+fn test_parse_ordinal_day() {
+    for (s, day) in [
+        ("1st", 1),
+        ("22nd", 22),
+        ("3rd", 3),
+        ("4th", 4),
+        ("31ST", 31),
+        ("1th", 1),
+    ] {
+        let parsed = s.parse::<DayOfMonth>().unwrap();
+        assert_eq!(parsed, DayOfMonth(day));
+        assert_eq!(parsed.to_string(), day.to_string());
+    }
+    assert!("22x".parse::<DayOfMonth>().is_err());
+    assert!("32nd".parse::<DayOfMonth>().is_err());
+    assert_eq!(
+        "22nd April 2021".parse::<Date>().unwrap(),
+        Date(Month::April, DayOfMonth(22), Year(2021))
+    );
+    assert_eq!(
+        "1st jan 2024 at 9:00 AM"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "1/1/2024 at 9:00 AM"
+    );
+}
+
+#[test]
+fn test_parse_noon_midnight() {
+    assert_eq!(
+        "noon".parse::<Time>().unwrap(),
+        Time(Hour::Hour24(12), Minute(0))
+    );
+    assert_eq!(
+        "Midnight".parse::<Time>().unwrap(),
+        Time(Hour::Hour24(0), Minute(0))
+    );
+    assert_eq!("noon".parse::<Time>().unwrap().to_string(), "12:00");
+    assert_eq!("midnight".parse::<Time>().unwrap().to_string(), "0:00");
+    assert!("teatime".parse::<Time>().is_err());
+    let expected = DateTime(
+        Date(Month::January, DayOfMonth(1), Year(2024)),
+        Time(Hour::Hour24(0), Minute(0)),
+    );
+    assert_eq!(
+        "1/1/2024 at midnight".parse::<DateTime>().unwrap(),
+        expected
+    );
+    assert_eq!("1/1/2024 midnight".parse::<DateTime>().unwrap(), expected);
+    assert_eq!(
+        "1/1/2024 at midnight".parse::<AbsoluteTime>().unwrap(),
+        AbsoluteTime::DateTime(expected)
+    );
+    assert_eq!(
+        "3 hours after 1 jan 2024 at noon"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "3 hours after 1/1/2024 at 12:00"
+    );
+    assert_eq!(
+        "tomorrow at noon".parse::<RelativeTime>().unwrap(),
+        RelativeTime::NamedAt(
+            NamedRelativeTime::Tomorrow,
+            Time(Hour::Hour24(12), Minute(0)).into()
+        )
+    );
+    assert!("1/1/2024 at teatime".parse::<DateTime>().is_err());
+}
+
+#[test]
+fn test_parse_clock_range() {
+    let on = |date: &str, time: &str| {
+        PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(
+            date.parse().unwrap(),
+            time.parse().unwrap(),
+        )))
+    };
+    assert_eq!(
+        "from 9 to 11 AM on 5/6/2024".parse::<TimeRange>().unwrap(),
+        TimeRange(on("5/6/2024", "9:00 AM"), on("5/6/2024", "11:00 AM"))
+    );
+    assert_eq!(
+        "from 9 to 11 AM on 5/6/2024"
+            .parse::<TimeExpression>()
+            .unwrap(),
+        TimeExpression::Range(TimeRange(
+            on("5/6/2024", "9:00 AM"),
+            on("5/6/2024", "11:00 AM")
+        ))
+    );
+    assert_eq!(
+        "from 11 to 1 PM on 5 June 2024"
+            .parse::<TimeRange>()
+            .unwrap(),
+        TimeRange(on("5/6/2024", "11:00 AM"), on("5/6/2024", "1:00 PM"))
+    );
+    assert_eq!(
+        "from 9:30 AM through 5 on 5/6/2024"
+            .parse::<TimeRange>()
+            .unwrap(),
+        TimeRange(on("5/6/2024", "9:30 AM"), on("5/6/2024", "5:00 PM"))
+    );
+    assert_eq!(
+        "from 9:15 to 17:45 on 5/6/2024"
+            .parse::<TimeRange>()
+            .unwrap(),
+        TimeRange(on("5/6/2024", "9:15"), on("5/6/2024", "17:45"))
+    );
+    let today = "from 2 to 4 PM".parse::<TimeRange>().unwrap();
+    assert_eq!(
+        today.to_string(),
+        "from today at 2:00 PM to today at 4:00 PM"
+    );
+    assert_eq!(today.to_string().parse::<TimeRange>().unwrap(), today);
+    assert_eq!(
+        "from 1/1/2023 through 15/1/2023"
+            .parse::<TimeRange>()
+            .unwrap(),
+        "from 1/1/2023 to 15/1/2023".parse::<TimeRange>().unwrap()
+    );
+    assert!("from 13 to 2 PM on 5/6/2024".parse::<TimeRange>().is_err());
+    assert!("from 25 to 26 on 5/6/2024".parse::<TimeRange>().is_err());
+    assert!("from 9 until 11 AM".parse::<TimeRange>().is_err());
+}
+
+#[test]
+fn test_time_unit_label() {
+    use TimeUnit::*;
+    for (unit, singular, plural) in [
+        (Minutes, "minute", "minutes"),
+        (Hours, "hour", "hours"),
+        (Days, "day", "days"),
+        (Weeks, "week", "weeks"),
+        (Months, "month", "months"),
+        (Years, "year", "years"),
+    ] {
+        assert_eq!(unit.label(0), plural);
+        assert_eq!(unit.label(1), singular);
+        assert_eq!(unit.label(2), plural);
+        assert_eq!(unit.as_ref(), plural);
+    }
+}
+
+#[test]
+fn test_point_in_time_cmp_resolved() {
+    use std::cmp::Ordering;
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let point = |s: &str| s.parse::<PointInTime>().unwrap();
+    assert_eq!(
+        point("1 day ago").cmp_resolved(&point("tomorrow"), now),
+        Ordering::Less
+    );
+    assert_eq!(
+        point("tomorrow").cmp_resolved(&point("1 day ago"), now),
+        Ordering::Greater
+    );
+    // structurally, absolute points always sort first
+    assert!(point("1/1/2030") < point("1 day ago"));
+    assert_eq!(
+        point("1/1/2030").cmp_resolved(&point("1 day ago"), now),
+        Ordering::Greater
+    );
+    assert_eq!(
+        point("16/3/2024").cmp_resolved(&point("tomorrow"), now),
+        Ordering::Equal
+    );
+    assert_eq!(
+        point("15/3/2024 at 12:00 PM").cmp_resolved(&point("15/3/2024 at 12:00"), now),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn test_parse_year_first_date() {
+    assert_eq!(
+        "2024/6/5".parse::<Date>().unwrap(),
+        Date(Month::June, DayOfMonth(5), Year(2024))
+    );
+    assert_eq!("2024/6/5".parse::<Date>().unwrap().to_string(), "5/6/2024");
+    assert_eq!(
+        "5/6/2024".parse::<Date>().unwrap(),
+        Date(Month::June, DayOfMonth(5), Year(2024))
+    );
+    assert_eq!(
+        "2024/12/31 at 11:59 PM".parse::<TimeExpression>().unwrap(),
+        "31/12/2024 at 11:59 PM".parse::<TimeExpression>().unwrap()
+    );
+    assert!("2024/13/5".parse::<Date>().is_err());
+    assert!("2024/6/32".parse::<Date>().is_err());
+    assert!("202/6/5".parse::<Date>().is_err());
+}
+
+#[test]
+fn test_relative_time_resolve_range() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let range = |s: &str| {
+        s.parse::<RelativeTime>()
+            .unwrap()
+            .resolve_range(now)
+            .to_string()
+    };
+    assert_eq!(
+        range("tomorrow"),
+        "from 16/3/2024 at 0:00 to 16/3/2024 at 23:59"
+    );
+    assert_eq!(
+        range("the day before yesterday"),
+        "from 13/3/2024 at 0:00 to 13/3/2024 at 23:59"
+    );
+    assert_eq!(
+        range("3 hours from now"),
+        "from 15/3/2024 at 13:30 to 15/3/2024 at 13:30"
+    );
+    assert_eq!(
+        range("now"),
+        "from 15/3/2024 at 10:30 to 15/3/2024 at 10:30"
+    );
+    assert_eq!(
+        range("next monday"),
+        "from 18/3/2024 at 0:00 to 18/3/2024 at 23:59"
+    );
+    assert_eq!(
+        range("next week"),
+        "from 18/3/2024 at 0:00 to 24/3/2024 at 23:59"
+    );
+    assert_eq!(
+        range("last month"),
+        "from 1/2/2024 at 0:00 to 29/2/2024 at 23:59"
+    );
+    assert_eq!(
+        range("next year"),
+        "from 1/1/2025 at 0:00 to 31/12/2025 at 23:59"
+    );
+    let tomorrow = "tomorrow"
+        .parse::<RelativeTime>()
+        .unwrap()
+        .resolve_range(now);
+    assert!(tomorrow.contains(&"16/3/2024 at 18:00".parse().unwrap(), now));
+    assert!(!tomorrow.contains(&"17/3/2024".parse().unwrap(), now));
+}
+
+#[test]
+fn test_time_24_00() {
+    let end_of_day = "24:00".parse::<Time>().unwrap();
+    assert_eq!(end_of_day, Time(Hour::Hour24(24), Minute(0)));
+    assert!("24:01".parse::<Time>().is_err());
+    assert!("24:30".parse::<Time>().is_err());
+    assert!("from 22:00 to 24:30 on 5/6/2024"
+        .parse::<TimeRange>()
+        .is_err());
+    assert!("from 22:00 to 24 on 5/6/2024".parse::<TimeRange>().is_ok());
+    assert_eq!(
+        end_of_day.normalize(),
+        (Time(Hour::Hour24(0), Minute(0)), true)
+    );
+    let time = "23:59".parse::<Time>().unwrap();
+    assert_eq!(time.normalize(), (time, false));
+    let time = "12:00 AM".parse::<Time>().unwrap();
+    assert_eq!(time.normalize(), (time, false));
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    assert_eq!(
+        "15/3/2024 at 24:00"
+            .parse::<PointInTime>()
+            .unwrap()
+            .cmp_resolved(&"16/3/2024".parse().unwrap(), now),
+        std::cmp::Ordering::Equal
+    );
+}
+
+#[test]
+fn test_duration_builder() {
+    assert_eq!(
+        Duration::builder().hours(2).minutes(30).build(),
+        "2 hours, 30 minutes".parse::<Duration>().unwrap()
+    );
+    assert_eq!(
+        Duration::builder()
+            .years(5)
+            .months(2)
+            .weeks(3)
+            .days(1)
+            .hours(4)
+            .minutes(11)
+            .build(),
+        "5 years, 2 months, 3 weeks, 1 day, 4 hours and 11 minutes"
+            .parse::<Duration>()
+            .unwrap()
+    );
+    assert_eq!(
+        Duration::builder().days(1).days(2).build(),
+        "1 day and 2 days".parse::<Duration>().unwrap()
+    );
+    assert_eq!(Duration::builder().build().total_minutes(), 0);
+    assert_eq!(
+        Duration::builder().years(u64::MAX).years(1).build().years,
+        Number(u64::MAX)
+    );
+}
+
+#[test]
+fn test_parse_office_hours() {
+    let date = Date(Month::June, DayOfMonth(5), Year(2024));
+    let at =
+        |h, m| PointInTime::Absolute(AbsoluteTime::DateTime(DateTime(date, Time(h, Minute(m)))));
+    assert_eq!(
+        "during office hours on 5/6/2024"
+            .parse::<TimeRange>()
+            .unwrap(),
+        TimeRange(at(Hour::Hour24(9), 0), at(Hour::Hour24(17), 0))
+    );
+    assert_eq!(
+        "working hours on 5/6/2024"
+            .parse::<TimeExpression>()
+            .unwrap(),
+        TimeExpression::Range(TimeRange(at(Hour::Hour24(9), 0), at(Hour::Hour24(17), 0)))
+    );
+    assert_eq!(
+        "office hours on 5/6/2024"
+            .parse::<TimeRange>()
+            .unwrap()
+            .to_string(),
+        "from 5/6/2024 at 9:00 to 5/6/2024 at 17:00"
+    );
+    let today = |h| {
+        PointInTime::Relative(RelativeTime::NamedAt(
+            NamedRelativeTime::Today,
+            Time(Hour::Hour24(h), Minute(0)).into(),
+        ))
+    };
+    assert_eq!(
+        "office hours".parse::<TimeRange>().unwrap(),
+        TimeRange(today(9), today(17))
+    );
+    let custom = OfficeHours {
+        start: Time(Hour::Hour12(8, AmPm::AM), Minute(30)),
+        end: Time(Hour::Hour12(4, AmPm::PM), Minute(30)),
+    };
+    assert_eq!(
+        TimeRange::parse_with_office_hours("during working hours on 5/6/2024", custom).unwrap(),
+        TimeRange(
+            at(Hour::Hour12(8, AmPm::AM), 30),
+            at(Hour::Hour12(4, AmPm::PM), 30)
+        )
+    );
+    assert!("office time on 5/6/2024".parse::<TimeRange>().is_err());
+}
+
+#[test]
+fn test_duration_postgres_interval() {
+    let duration = Duration::builder()
+        .years(1)
+        .months(2)
+        .days(3)
+        .hours(4)
+        .minutes(5)
+        .build();
+    assert_eq!(
+        duration.to_postgres_interval(),
+        "1 year 2 mons 3 days 04:05:00"
+    );
+    assert_eq!(
+        Duration::parse_postgres_interval(&duration.to_postgres_interval()).unwrap(),
+        duration
+    );
+    for duration in [
+        Duration::builder().build(),
+        Duration::builder().months(1).build(),
+        Duration::builder().years(3).days(1).build(),
+        Duration::builder().hours(36).minutes(59).build(),
+    ] {
+        assert_eq!(
+            Duration::parse_postgres_interval(&duration.to_postgres_interval()).unwrap(),
+            duration
+        );
+    }
+    assert_eq!(
+        Duration::builder().build().to_postgres_interval(),
+        "00:00:00"
+    );
+    assert_eq!(
+        Duration::builder()
+            .weeks(2)
+            .days(1)
+            .build()
+            .to_postgres_interval(),
+        "15 days"
+    );
+    assert_eq!(
+        Duration::parse_postgres_interval("1 day 00:30").unwrap(),
+        Duration::builder().days(1).minutes(30).build()
+    );
+    assert!(Duration::parse_postgres_interval("").is_err());
+    assert!(Duration::parse_postgres_interval("-1 days").is_err());
+    assert!(Duration::parse_postgres_interval("3 weeks").is_err());
+    assert!(Duration::parse_postgres_interval("00:00:30").is_err());
+    assert!(Duration::parse_postgres_interval("01:00:00 1 day").is_err());
+    // minutes of 60 or more are carried into hours
+    for (input, interval) in [
+        ("90 minutes", "01:30:00"),
+        ("1 hour, 75 minutes", "02:15:00"),
+    ] {
+        let duration = input.parse::<Duration>().unwrap();
+        assert_eq!(duration.to_postgres_interval(), interval);
+        let parsed = Duration::parse_postgres_interval(interval).unwrap();
+        assert_eq!(parsed.total_minutes(), duration.total_minutes());
+        assert_eq!(parsed.to_postgres_interval(), interval);
+    }
+    assert_eq!(
+        Duration::builder()
+            .hours(u64::MAX)
+            .minutes(60)
+            .build()
+            .to_postgres_interval(),
+        format!("{}:00:00", u64::MAX)
+    );
+}
+
+#[test]
+fn test_month_name() {
+    assert_eq!(Month::April.name(), "April");
+    assert_eq!(MonthName(Month::April).to_string(), "April");
+    assert_eq!(Month::April.to_string(), "4");
+    for month in [
+        Month::January,
+        Month::May,
+        Month::September,
+        Month::December,
+    ] {
+        assert_eq!(
+            month.name().parse::<Month>().unwrap(),
+            month,
+            "{} should parse back",
+            MonthName(month)
+        );
+    }
+}
+
+#[test]
+fn test_duration_std_conversions() {
+    let std_duration = std::time::Duration::from_secs(90 * 60);
+    let duration = Duration::from(std_duration);
+    assert_eq!(duration, Duration::builder().hours(1).minutes(30).build());
+    assert_eq!(
+        std::time::Duration::try_from(duration).unwrap(),
+        std_duration
+    );
+    assert_eq!(
+        Duration::from(std::time::Duration::from_secs(8 * 24 * 60 * 60 + 59)),
+        Duration::builder().weeks(1).days(1).build()
+    );
+    let calendar = Duration::builder().months(1).days(2).build();
+    assert_eq!(
+        std::time::Duration::try_from(calendar),
+        Err(CalendarDurationError { duration: calendar })
+    );
+    assert_eq!(
+        calendar.to_std_approximate(),
+        std::time::Duration::from_secs(32 * 24 * 60 * 60)
+    );
+    assert_eq!(
+        Duration::builder()
+            .years(u64::MAX)
+            .build()
+            .to_std_approximate(),
+        std::time::Duration::from_secs(u64::MAX)
+    );
+}
+
+#[test]
+fn test_parse_all_day() {
+    let date = Date(Month::June, DayOfMonth(5), Year(2024));
+    assert_eq!(
+        "all day 5/6/2024".parse::<TimeExpression>().unwrap(),
+        TimeExpression::AllDay(date)
+    );
+    assert_eq!(
+        "5/6/2024 all day".parse::<TimeExpression>().unwrap(),
+        TimeExpression::AllDay(date)
+    );
+    assert_eq!(
+        "5/6/2024 all day"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "all day 5/6/2024"
+    );
+    assert_eq!(
+        "All Day 5 june 2024".parse::<TimeExpression>().unwrap(),
+        TimeExpression::AllDay(date)
+    );
+    assert_eq!(
+        date.all_day(),
+        "from 5/6/2024 at 0:00 to 5/6/2024 at 23:59"
+            .parse::<TimeRange>()
+            .unwrap()
+    );
+    let range = date.all_day();
+    for point in ["5/6/2024", "5/6/2024 at 12:00", "5/6/2024 at 23:59"] {
+        let point = point.parse::<AbsoluteTime>().unwrap();
+        assert!(range.contains(&point, date.midnight()));
+    }
+    for point in ["4/6/2024 at 23:59", "6/6/2024"] {
+        let point = point.parse::<AbsoluteTime>().unwrap();
+        assert!(!range.contains(&point, date.midnight()));
+    }
+    assert!("all night 5/6/2024".parse::<TimeExpression>().is_err());
+    assert!("all day".parse::<TimeExpression>().is_err());
+}
+
+#[test]
+fn test_offset_date_time() {
+    let date_time = "15/3/2024 at 14:30 +02:00"
+        .parse::<OffsetDateTime>()
+        .unwrap();
+    assert_eq!(date_time.to_string(), "15/3/2024 at 14:30 +02:00");
+    assert_eq!(
+        date_time.to_utc(),
+        "15/3/2024 at 12:30 +00:00"
+            .parse::<OffsetDateTime>()
+            .unwrap()
+    );
+    assert_eq!(date_time.to_utc().to_string(), "15/3/2024 at 12:30 +00:00");
+    assert_eq!(
+        date_time.to_offset(UtcOffset(2 * 60)).to_utc(),
+        date_time.to_utc()
+    );
+    // across a day (and year) boundary
+    assert_eq!(
+        "31/12/2023 at 23:30 -05:00"
+            .parse::<OffsetDateTime>()
+            .unwrap()
+            .to_utc()
+            .to_string(),
+        "1/1/2024 at 4:30 +00:00"
+    );
+    assert_eq!(
+        "1/3/2024 at 1:00 AM JST"
+            .parse::<OffsetDateTime>()
+            .unwrap()
+            .to_utc()
+            .to_string(),
+        "29/2/2024 at 16:00 +00:00"
+    );
+    let naive = "15/3/2024 at 14:30".parse::<DateTime>().unwrap();
+    assert_eq!(naive.with_offset(UtcOffset(0)).to_utc().0, naive);
+    assert!("15/3/2024 at 14:30".parse::<OffsetDateTime>().is_err());
+}
+
+#[test]
+fn test_duration_is_zero() {
+    let zero = Duration::builder().build();
+    assert!(zero.is_zero());
+    assert_eq!(zero.to_string(), "");
+    assert_eq!(zero.to_string_nonempty(), "0 minutes");
+    let duration = "1 year, 3 minutes".parse::<Duration>().unwrap();
+    assert!(!duration.is_zero());
+    assert!(!Duration::builder().minutes(1).build().is_zero());
+    assert_eq!(duration.to_string_nonempty(), duration.to_string());
+    assert_eq!(duration - duration, zero);
+}
+
+#[test]
+fn test_parse_a_an_quantity() {
+    let day = Duration::builder().days(1).build();
+    assert_eq!(
+        "a day ago".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Directional {
+            duration: day,
+            dir: TimeDirection::Ago
+        }
+    );
+    assert_eq!(
+        "an hour from now".parse::<TimeExpression>().unwrap(),
+        TimeExpression::Specific(PointInTime::Relative(RelativeTime::Directional {
+            duration: Duration::builder().hours(1).build(),
+            dir: TimeDirection::FromNow
+        }))
+    );
+    assert_eq!(
+        "a week before tomorrow".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Directional {
+            duration: Duration::builder().weeks(1).build(),
+            dir: TimeDirection::BeforeNamed(NamedRelativeTime::Tomorrow)
+        }
+    );
+    assert_eq!(
+        "a day and an hour".parse::<TimeExpression>().unwrap(),
+        TimeExpression::Duration(Duration::builder().days(1).hours(1).build())
+    );
+    assert_eq!(
+        "2 days and a minute".parse::<Duration>().unwrap(),
+        Duration::builder().days(2).minutes(1).build()
+    );
+    assert_eq!(
+        "in a month".parse::<RelativeTime>().unwrap().to_string(),
+        "1 month from now"
+    );
+    assert!("a tomorrow".parse::<TimeExpression>().is_err());
+    assert!("an".parse::<Duration>().is_err());
+}
+
+#[test]
+fn test_parse_british_week_today() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let week_today = "a week today".parse::<RelativeTime>().unwrap();
+    assert_eq!(
+        week_today,
+        RelativeTime::Directional {
+            duration: Duration::builder().weeks(1).build(),
+            dir: TimeDirection::AfterNamed(NamedRelativeTime::Today)
+        }
+    );
+    assert_eq!(week_today.to_string(), "1 week after today");
+    assert_eq!(
+        week_today.resolve(now),
+        "22/3/2024 at 0:00".parse::<DateTime>().unwrap()
+    );
+    let fortnight_tomorrow = "a fortnight tomorrow".parse::<TimeExpression>().unwrap();
+    assert_eq!(fortnight_tomorrow.to_string(), "2 weeks after tomorrow");
+    let TimeExpression::Specific(point) = fortnight_tomorrow else {
+        panic!("expected a point in time");
+    };
+    assert_eq!(
+        point.resolve(now),
+        "30/3/2024 at 0:00".parse::<DateTime>().unwrap()
+    );
+    assert_eq!(
+        "2 fortnights".parse::<Duration>().unwrap(),
+        Duration::builder().weeks(4).build()
+    );
+    assert!("3 days yesterday".parse::<RelativeTime>().is_err());
+}
+
+#[test]
+fn test_parse_fractional_hours() {
+    let minutes = |n| Duration::builder().minutes(n).build();
+    assert_eq!("half an hour".parse::<Duration>().unwrap(), minutes(30));
+    assert_eq!(
+        "half an hour".parse::<Duration>().unwrap().to_string(),
+        "30 minutes"
+    );
+    assert_eq!(
+        "quarter of an hour".parse::<Duration>().unwrap(),
+        minutes(15)
+    );
+    assert_eq!(
+        "a quarter of an hour".parse::<Duration>().unwrap(),
+        minutes(15)
+    );
+    assert_eq!("quarter hour".parse::<Duration>().unwrap(), minutes(15));
+    assert_eq!(
+        "half an hour and 10 minutes".parse::<Duration>().unwrap(),
+        minutes(40)
+    );
+    assert_eq!(
+        "2 hours and a half hour".parse::<Duration>().unwrap(),
+        Duration::builder().hours(2).minutes(30).build()
+    );
+    assert_eq!(
+        "in half an hour"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "30 minutes from now"
+    );
+    assert_eq!(
+        "quarter of an hour ago"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "15 minutes ago"
+    );
+    assert!("half a day".parse::<Duration>().is_err());
+    assert!("quarter of".parse::<Duration>().is_err());
+}
+
+#[test]
+fn test_display_len_hint() {
+    for expr in [
+        "3 days after 18/3/2024",
+        "5 years, 2 months, 3 weeks, 4 days, 18 hours, 59 minutes before 31/12/2024 at 11:59 PM",
+        "the day before yesterday at 12:00 PM",
+        "this time next wednesday",
+        "now +05:30",
+        "from 1/1/2024 at 10:00 to 5 hours after tomorrow",
+        "until 34445721600 minutes before the day before yesterday",
+        "all day 31/12/65535",
+        "65535 years, 1 minute",
+        "next week",
+    ] {
+        let expr = expr.parse::<TimeExpression>().unwrap();
+        assert!(
+            expr.display_len_hint() >= expr.to_string().len(),
+            "hint for {expr} is too small"
+        );
+    }
+    let expr = TimeExpression::Duration(Duration::builder().years(u64::MAX).minutes(1).build());
+    assert!(expr.display_len_hint() >= expr.to_string().len());
+    let mut buffer = String::with_capacity(
+        "in 2 days"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .display_len_hint(),
+    );
+    let capacity = buffer.capacity();
+    buffer.push_str("2 days from now");
+    assert_eq!(buffer.capacity(), capacity);
+}
+
+#[test]
+fn test_parse_error_offset() {
+    let err = "3 days agoo".parse::<TimeExpression>().unwrap_err();
+    assert_eq!(err.offset, "3 days ".len());
+    assert_eq!(err.message, "unexpected token");
+    assert_eq!(err.to_string(), "unexpected token (at offset 7)");
+    let err = "from 1/1/2024 to".parse::<TimeRange>().unwrap_err();
+    assert_eq!(err.offset, "from 1/1/2024 to".len());
+    // the offending token was already consumed, so the offset points just past it
+    let err = "60".parse::<Minute>().unwrap_err();
+    assert_eq!(err.offset, 2);
+    assert_eq!(err.message, "minute must be between 0 and 59 (inclusive)");
+    // offsets are in characters rather than bytes
+    let err = "3 dáys".parse::<Duration>().unwrap_err();
+    assert_eq!(err.offset, 6);
+    assert!("3 days ago".parse::<TimeExpression>().is_ok());
+}
+
+#[test]
+fn test_parse_rest_of_period() {
+    // a Friday
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let resolve = |s: &str| {
+        let range = s.parse::<TimeRange>().unwrap();
+        (range.0.resolve(now), range.1.resolve(now))
+    };
+    let date_time = |s: &str| s.parse::<DateTime>().unwrap();
+    assert_eq!(
+        resolve("rest of the day"),
+        (now, date_time("15/3/2024 at 23:59"))
+    );
+    assert_eq!(
+        resolve("rest of the week"),
+        (now, date_time("17/3/2024 at 23:59"))
+    );
+    assert_eq!(
+        resolve("rest of the month"),
+        (now, date_time("31/3/2024 at 23:59"))
+    );
+    assert_eq!(
+        resolve("rest of year"),
+        (now, date_time("31/12/2024 at 23:59"))
+    );
+    assert_eq!(
+        "rest of the day".parse::<TimeRange>().unwrap(),
+        "from now to end of day".parse::<TimeRange>().unwrap()
+    );
+    for s in [
+        "rest of the day",
+        "rest of the week",
+        "rest of the month",
+        "rest of the year",
+    ] {
+        assert_eq!(s.parse::<TimeExpression>().unwrap().to_string(), s);
+    }
+    // Sunday is the last day of the week
+    let sunday = "17/3/2024 at 8:00".parse::<DateTime>().unwrap();
+    assert_eq!(
+        "end of the week"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .resolve(sunday),
+        date_time("17/3/2024 at 23:59")
+    );
+    assert!("rest of the decade".parse::<TimeRange>().is_err());
+}
+
+#[test]
+fn test_time_same_instant() {
+    let pm = Time(Hour::Hour12(2, AmPm::PM), Minute(30));
+    let h24 = Time(Hour::Hour24(14), Minute(30));
+    assert_ne!(pm, h24);
+    assert!(pm.same_instant(&h24));
+    assert!(h24.same_instant(&pm));
+    let midnight_12h = Time(Hour::Hour12(12, AmPm::AM), Minute(0));
+    let midnight_24h = Time(Hour::Hour24(0), Minute(0));
+    assert!(midnight_12h.same_instant(&midnight_24h));
+    assert!("midnight"
+        .parse::<Time>()
+        .unwrap()
+        .same_instant(&midnight_12h));
+    assert!(!Time(Hour::Hour12(12, AmPm::PM), Minute(0)).same_instant(&midnight_24h));
+    assert!(!pm.same_instant(&Time(Hour::Hour24(14), Minute(31))));
+    assert!(!Time(Hour::Hour24(24), Minute(0)).same_instant(&midnight_24h));
+}
+
+#[test]
+fn test_parse_this_unit() {
+    assert_eq!(
+        "this monday".parse::<RelativeTime>().unwrap(),
+        RelativeTime::This(RelativeTimeUnit::Monday)
+    );
+    assert_eq!(
+        "this friday".parse::<TimeExpression>().unwrap().to_string(),
+        "this Friday"
+    );
+    assert_eq!(
+        "this week".parse::<RelativeTime>().unwrap(),
+        RelativeTime::This(RelativeTimeUnit::Week)
+    );
+    assert_eq!(
+        "this time tomorrow".parse::<RelativeTime>().unwrap(),
+        RelativeTime::ThisTimeNamed(NamedRelativeTime::Tomorrow)
+    );
+    // a Friday
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let resolve = |s: &str| s.parse::<RelativeTime>().unwrap().resolve(now);
+    let date_time = |s: &str| s.parse::<DateTime>().unwrap();
+    assert_eq!(resolve("this monday"), date_time("11/3/2024 at 0:00"));
+    assert_eq!(resolve("this friday"), date_time("15/3/2024 at 0:00"));
+    assert_eq!(resolve("this sunday"), date_time("17/3/2024 at 0:00"));
+    assert_eq!(resolve("this week"), date_time("11/3/2024 at 0:00"));
+    assert_eq!(resolve("this month"), date_time("1/3/2024 at 0:00"));
+    assert_eq!(resolve("this year"), date_time("1/1/2024 at 0:00"));
+    assert_eq!(
+        "this week"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .resolve_range(now)
+            .to_string(),
+        "from 11/3/2024 at 0:00 to 17/3/2024 at 23:59"
+    );
+    assert!("this fortnight".parse::<RelativeTime>().is_err());
+}
+
+#[test]
+fn test_time_range_iter() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let range = "from 1/1/2023 to 5/1/2023".parse::<TimeRange>().unwrap();
+    let days = range.iter_days(now).collect::<Vec<_>>();
+    assert_eq!(
+        days,
+        ["1/1/2023", "2/1/2023", "3/1/2023", "4/1/2023", "5/1/2023"]
+            .map(|s| s.parse::<Date>().unwrap())
+    );
+    let step = "2 days".parse::<Duration>().unwrap();
+    assert_eq!(
+        range
+            .iter_step(step, now)
+            .map(|date_time| date_time.0)
+            .collect::<Vec<_>>(),
+        ["1/1/2023", "3/1/2023", "5/1/2023"].map(|s| s.parse::<Date>().unwrap())
+    );
+    // endpoints may be reversed or relative
+    let reversed = "from 5/1/2023 to 1/1/2023".parse::<TimeRange>().unwrap();
+    assert_eq!(reversed.iter_days(now).count(), 5);
+    let relative = "from yesterday to tomorrow".parse::<TimeRange>().unwrap();
+    assert_eq!(
+        relative.iter_days(now).collect::<Vec<_>>(),
+        ["14/3/2024", "15/3/2024", "16/3/2024"].map(|s| s.parse::<Date>().unwrap())
+    );
+    let zero = Duration::builder().build();
+    assert_eq!(range.iter_step(zero, now).count(), 1);
+    let month = Duration::builder().months(1).build();
+    let year = "from 31/1/2024 to 31/12/2024".parse::<TimeRange>().unwrap();
+    assert_eq!(year.iter_step(month, now).count(), 12);
+}
+
+#[test]
+fn test_parse_qualified_time() {
+    let time = Time(Hour::Hour12(3, AmPm::PM), Minute(0));
+    let sharp = "3:00 PM sharp".parse::<QualifiedTime>().unwrap();
+    assert_eq!(sharp, QualifiedTime(time, Precision::Exact));
+    assert_eq!(sharp.to_string(), "3:00 PM sharp");
+    assert_eq!(sharp.0, "3:00 PM".parse::<Time>().unwrap());
+    let exactly = "exactly 14:00".parse::<QualifiedTime>().unwrap();
+    assert_eq!(
+        exactly,
+        QualifiedTime(Time(Hour::Hour24(14), Minute(0)), Precision::Exact)
+    );
+    assert_eq!(exactly.to_string(), "14:00 sharp");
+    assert_eq!(
+        "exactly noon sharp".parse::<QualifiedTime>().unwrap(),
+        QualifiedTime(Time(Hour::Hour24(12), Minute(0)), Precision::Exact)
+    );
+    let plain = "3:00 PM".parse::<QualifiedTime>().unwrap();
+    assert_eq!(plain, QualifiedTime(time, Precision::Unqualified));
+    assert_eq!(plain.to_string(), "3:00 PM");
+    assert!("roughly 3:00 PM".parse::<QualifiedTime>().is_err());
+    assert!("3:00 PM flat".parse::<QualifiedTime>().is_err());
+}
+
+#[test]
+fn test_weekday_iso_number() {
+    assert_eq!(Weekday::Monday.iso_number(), 1);
+    assert_eq!(Weekday::Sunday.iso_number(), 7);
+    assert_eq!(Weekday::from_iso_number(1), Some(Weekday::Monday));
+    assert_eq!(Weekday::from_iso_number(7), Some(Weekday::Sunday));
+    assert_eq!(Weekday::from_iso_number(0), None);
+    assert_eq!(Weekday::from_iso_number(8), None);
+    for number in 1..=7 {
+        let weekday = Weekday::from_iso_number(number).unwrap();
+        assert_eq!(weekday.iso_number(), number);
+    }
+    assert_eq!(Weekday::Wednesday.to_string(), "Wednesday");
+    assert_eq!(
+        RelativeTimeUnit::from(Weekday::Thursday),
+        RelativeTimeUnit::Thursday
+    );
+    assert_eq!(
+        "1/1/2024".parse::<Date>().unwrap().weekday(),
+        Weekday::Monday
+    );
+    assert_eq!(
+        "31/12/2023".parse::<Date>().unwrap().weekday().iso_number(),
+        7
+    );
+}
+
+#[test]
+fn test_parse_recurrence() {
+    let every_two_weeks = "every 2 weeks".parse::<TimeExpression>().unwrap();
+    assert_eq!(
+        every_two_weeks,
+        TimeExpression::Recurrence(Recurrence {
+            interval: Duration::builder().weeks(2).build(),
+            start: None,
+        })
+    );
+    assert_eq!(every_two_weeks.to_string(), "every 2 weeks");
+    let every_day = "every day".parse::<TimeExpression>().unwrap();
+    assert_eq!(
+        every_day,
+        TimeExpression::Recurrence(Recurrence {
+            interval: Duration::builder().days(1).build(),
+            start: None,
+        })
+    );
+    assert_eq!(every_day.to_string(), "every day");
+    assert_eq!(
+        "every 1 month".parse::<Recurrence>().unwrap().to_string(),
+        "every month"
+    );
+    assert_eq!(
+        "every fortnight".parse::<Recurrence>().unwrap().to_string(),
+        "every 2 weeks"
+    );
+    assert_eq!(
+        "every 1 hour and 30 minutes"
+            .parse::<Recurrence>()
+            .unwrap()
+            .to_string(),
+        "every 1 hour, 30 minutes"
+    );
+    assert_eq!(
+        "every 2 weeks"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .temporal_position("15/3/2024 at 10:30".parse().unwrap()),
+        None
+    );
+    assert!("every 0 days".parse::<Recurrence>().is_err());
+    assert!("every".parse::<Recurrence>().is_err());
+    assert!("every tuesday".parse::<Recurrence>().is_err());
+}
+
+#[test]
+fn test_date_new() {
+    assert_eq!(
+        Date::new(20, 4, 2021),
+        Ok(Date(Month::April, DayOfMonth(20), Year(2021)))
+    );
+    assert_eq!(
+        Date::new(29, 2, 2024),
+        Ok(Date(Month::February, DayOfMonth(29), Year(2024)))
+    );
+    assert_eq!(Date::new(40, 1, 2024), Err(DateError::InvalidDay(40)));
+    assert_eq!(Date::new(0, 1, 2024), Err(DateError::InvalidDay(0)));
+    assert_eq!(Date::new(1, 13, 2024), Err(DateError::InvalidMonth(13)));
+    assert_eq!(Date::new(1, 0, 2024), Err(DateError::InvalidMonth(0)));
+    assert_eq!(
+        Date::new(31, 4, 2024),
+        Err(DateError::DayNotInMonth {
+            day: 31,
+            month: Month::April,
+            year: Year(2024)
+        })
+    );
+    assert_eq!(
+        Date::new(29, 2, 1900).unwrap_err().to_string(),
+        "day 29 does not exist in February 1900"
+    );
+    assert!(Date::new(29, 2, 2000).is_ok());
+    assert_eq!(
+        Date::new(1, 13, 2024).unwrap_err().to_string(),
+        "month must be between 1 and 12 (inclusive), got 13"
+    );
+}
+
+#[test]
+fn test_parse_date_first_clock_range() {
+    let at = |s: &str| PointInTime::Absolute(AbsoluteTime::DateTime(s.parse().unwrap()));
+    let range = "5/6/2024 from noon to midnight"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert_eq!(
+        range,
+        TimeRange(at("5/6/2024 at 12:00"), at("6/6/2024 at 0:00"))
+    );
+    assert_eq!(
+        range.to_string(),
+        "from 5/6/2024 at 12:00 to 6/6/2024 at 0:00"
+    );
+    assert_eq!(
+        "5/6/2024 from 22:00 to 02:00"
+            .parse::<TimeExpression>()
+            .unwrap(),
+        TimeExpression::Range(TimeRange(at("5/6/2024 at 22:00"), at("6/6/2024 at 2:00")))
+    );
+    assert_eq!(
+        "5/6/2024 from 9 to 5 PM".parse::<TimeRange>().unwrap(),
+        TimeRange(at("5/6/2024 at 9:00 AM"), at("5/6/2024 at 5:00 PM"))
+    );
+    assert_eq!(
+        "5/6/2024 from 18:00 to 24:00".parse::<TimeRange>().unwrap(),
+        TimeRange(at("5/6/2024 at 18:00"), at("6/6/2024 at 0:00"))
+    );
+    assert_eq!(
+        "30/6/2024 from 11 PM to 1 AM".parse::<TimeRange>().unwrap(),
+        TimeRange(at("30/6/2024 at 11:00 PM"), at("1/7/2024 at 1:00 AM"))
+    );
+    // keyword endpoints also work in the date-last form
+    assert_eq!(
+        "from 9:30 to noon on 5/6/2024"
+            .parse::<TimeRange>()
+            .unwrap(),
+        TimeRange(at("5/6/2024 at 9:30"), at("5/6/2024 at 12:00"))
+    );
+    // `noon tomorrow` is still a point in time
+    assert!(matches!(
+        "from noon tomorrow to 5/6/2024"
+            .parse::<TimeRange>()
+            .unwrap()
+            .0,
+        PointInTime::Relative(RelativeTime::NamedAt(..))
+    ));
+    assert!("5/6/2024 from 22:00".parse::<TimeExpression>().is_err());
+}
+
+#[test]
+fn test_duration_eq_normalized() {
+    let parse = |s: &str| s.parse::<Duration>().unwrap();
+    assert!(parse("60 minutes").eq_normalized(&parse("1 hour")));
+    assert_ne!(parse("60 minutes"), parse("1 hour"));
+    assert!(parse("90 minutes").eq_normalized(&parse("1 hour, 30 minutes")));
+    assert_ne!(parse("90 minutes"), parse("1 hour, 30 minutes"));
+    assert!(parse("14 days").eq_normalized(&parse("2 weeks")));
+    assert!(parse("24 months").eq_normalized(&parse("2 years")));
+    // weeks are never carried into months
+    assert!(!parse("4 weeks").eq_normalized(&parse("1 month")));
+    assert!(!parse("61 minutes").eq_normalized(&parse("1 hour")));
+}
+
+#[test]
+fn test_date_time_to_string_no_at() {
+    for s in [
+        "5/6/2024 at 14:30",
+        "1/1/2000 at 3:05 PM",
+        "31/12/1999 at 0:00",
+    ] {
+        let dt = s.parse::<DateTime>().unwrap();
+        assert_eq!(dt.to_string(), s);
+        let no_at = dt.to_string_no_at();
+        assert!(!no_at.contains(" at "));
+        assert_eq!(no_at.parse::<DateTime>().unwrap(), dt);
+    }
+}
+
+#[test]
+fn test_parse_iso_dates() {
+    let date = "2021-04-20".parse::<Date>().unwrap();
+    assert_eq!(date, Date(Month::April, DayOfMonth(20), Year(2021)));
+    assert_eq!(date.to_string(), "20/4/2021");
+    assert_eq!(
+        parse2::<Date>(quote!(2021 - 04 - 20)).unwrap(),
+        "20/4/2021".parse::<Date>().unwrap()
+    );
+    assert_eq!(
+        "2021-04-20T14:00".parse::<DateTime>().unwrap(),
+        DateTime(date, Time(Hour::Hour24(14), Minute(0)))
+    );
+    assert_eq!(
+        "2021-04-20t9:30 PM".parse::<DateTime>().unwrap(),
+        DateTime(date, Time(Hour::Hour12(9, AmPm::PM), Minute(30)))
+    );
+    assert_eq!(
+        "2021-04-20 at 14:00".parse::<DateTime>().unwrap(),
+        "20/4/2021 at 14:00".parse::<DateTime>().unwrap()
+    );
+    assert_eq!(
+        "2021-04-20T14:00".parse::<TimeExpression>().unwrap(),
+        "20/4/2021 at 14:00".parse::<TimeExpression>().unwrap()
+    );
+    assert!("2021-04-20T14:00".parse::<Date>().is_err());
+    assert!("2021-04-20T25:00".parse::<DateTime>().is_err());
+    assert!("2021-13-20".parse::<Date>().is_err());
+    assert!("2021-04-32".parse::<Date>().is_err());
+    assert!("2021-04-20x".parse::<Date>().is_err());
+    assert!("21-04-20".parse::<Date>().is_err());
+}
+
+#[test]
+fn test_recurrence_starting() {
+    let recurrence = "every 2 weeks starting 1/1/2024"
+        .parse::<Recurrence>()
+        .unwrap();
+    assert_eq!(
+        recurrence,
+        Recurrence {
+            interval: Duration::builder().weeks(2).build(),
+            start: Some(PointInTime::Absolute(AbsoluteTime::Date(
+                "1/1/2024".parse().unwrap()
+            ))),
+        }
+    );
+    assert_eq!(recurrence.to_string(), "every 2 weeks starting 1/1/2024");
+    assert_eq!(
+        "every 2 weeks starting 1/1/2024"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "every 2 weeks starting 1/1/2024"
+    );
+    let at = |s: &str| s.parse::<DateTime>().unwrap();
+    // occurrences align to the anchor rather than to `now`
+    assert_eq!(
+        recurrence.next_after(at("10/1/2024 at 12:00")),
+        at("15/1/2024 at 0:00")
+    );
+    assert_eq!(
+        recurrence.next_after(at("15/1/2024 at 0:00")),
+        at("29/1/2024 at 0:00")
+    );
+    assert_eq!(
+        recurrence.next_after(at("1/1/2025 at 0:00")),
+        at("13/1/2025 at 0:00")
+    );
+    // a start in the future is itself the next occurrence
+    assert_eq!(
+        recurrence.next_after(at("1/6/2023 at 9:00")),
+        at("1/1/2024 at 0:00")
+    );
+    // calendar intervals are applied relative to the anchor, so month ends don't drift
+    let monthly = "every month starting 31/1/2024 at 9:00"
+        .parse::<Recurrence>()
+        .unwrap();
+    assert_eq!(
+        monthly.next_after(at("1/2/2024 at 0:00")),
+        at("29/2/2024 at 9:00")
+    );
+    assert_eq!(
+        monthly.next_after(at("29/2/2024 at 9:00")),
+        at("31/3/2024 at 9:00")
+    );
+    assert_eq!(
+        monthly.next_after(at("1/5/2124 at 0:00")),
+        at("31/5/2124 at 9:00")
+    );
+    // relative anchors are resolved against `now`
+    let daily = "every day starting tomorrow".parse::<Recurrence>().unwrap();
+    assert_eq!(
+        daily.next_after(at("10/1/2024 at 12:00")),
+        at("11/1/2024 at 0:00")
+    );
+    // unanchored recurrences are relative to `now`
+    let unanchored = "every 2 weeks".parse::<Recurrence>().unwrap();
+    assert_eq!(
+        unanchored.next_after(at("10/1/2024 at 12:00")),
+        at("24/1/2024 at 12:00")
+    );
+    assert!("every 2 weeks from 1/1/2024".parse::<Recurrence>().is_err());
+    assert!("every 2 weeks starting".parse::<Recurrence>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_resolution_report() {
+    use serde_json::json;
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let report = |s: &str| s.parse::<TimeExpression>().unwrap().resolution_report(now);
+    assert_eq!(
+        report("from yesterday to 3 hours from now"),
+        json!({
+            "kind": "range",
+            "start": "14/3/2024 at 0:00",
+            "end": "15/3/2024 at 13:30",
+            "required_now": true,
+        })
+    );
+    assert_eq!(
+        report("from 1/1/2024 to 2/1/2024")["required_now"],
+        json!(false)
+    );
+    assert_eq!(
+        report("tomorrow"),
+        json!({"kind": "point", "instant": "16/3/2024 at 0:00", "required_now": true})
+    );
+    assert_eq!(
+        report("3 days"),
+        json!({"kind": "duration", "duration": "3 days", "required_now": false})
+    );
+    assert_eq!(report("from 1/1/2024")["kind"], json!("open_range"));
+    assert_eq!(
+        report("all day 1/1/2024")["end"],
+        json!("1/1/2024 at 23:59")
+    );
+    assert_eq!(
+        report("every 2 weeks starting 1/3/2024"),
+        json!({
+            "kind": "recurrence",
+            "interval": "2 weeks",
+            "next": "29/3/2024 at 0:00",
+            "required_now": false,
+        })
+    );
+}
+
+#[test]
+fn test_to_iso8601() {
+    assert_eq!(
+        Date(Month::April, DayOfMonth(5), Year(2021)).to_iso8601(),
+        "2021-04-05"
+    );
+    assert_eq!(
+        Date(Month::December, DayOfMonth(31), Year(987)).to_iso8601(),
+        "0987-12-31"
+    );
+    assert_eq!(Time(Hour::Hour24(0), Minute(0)).to_iso8601(), "00:00");
+    assert_eq!(Time(Hour::Hour24(24), Minute(0)).to_iso8601(), "24:00");
+    assert_eq!(
+        Time(Hour::Hour12(12, AmPm::PM), Minute(1)).to_iso8601(),
+        "12:01"
+    );
+    assert_eq!(
+        Time(Hour::Hour12(7, AmPm::PM), Minute(45)).to_iso8601(),
+        "19:45"
+    );
+    let dt = "20/4/2021 at 9:30 AM".parse::<DateTime>().unwrap();
+    assert_eq!(dt.to_iso8601(), "2021-04-20T09:30");
+    // the human readable form is unaffected
+    assert_eq!(dt.to_string(), "20/4/2021 at 9:30 AM");
+    let reparsed = dt.to_iso8601().parse::<DateTime>().unwrap();
+    assert!(reparsed.1.same_instant(&dt.1));
+    assert_eq!(reparsed.0, dt.0);
+}
+
+#[test]
+fn test_parse_in_duration_time() {
+    let in_two_days = "in 2 days".parse::<RelativeTime>().unwrap();
+    assert_eq!(
+        "in two days' time".parse::<RelativeTime>().unwrap(),
+        in_two_days
+    );
+    assert_eq!(
+        "in 2 days' time".parse::<RelativeTime>().unwrap(),
+        in_two_days
+    );
+    assert_eq!(
+        "in 2 days time".parse::<RelativeTime>().unwrap(),
+        in_two_days
+    );
+    assert_eq!(
+        "in 2 days' time"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .to_string(),
+        "2 days from now"
+    );
+    let in_an_hour = "in an hour's time".parse::<TimeExpression>().unwrap();
+    assert_eq!(
+        in_an_hour,
+        "1 hour from now".parse::<TimeExpression>().unwrap()
+    );
+    assert_eq!(in_an_hour.to_string(), "1 hour from now");
+    assert_eq!(
+        "in 1 hour, 30 minutes' time"
+            .parse::<RelativeTime>()
+            .unwrap(),
+        "in 1 hour, 30 minutes".parse::<RelativeTime>().unwrap()
+    );
+    assert!("in an hour's".parse::<RelativeTime>().is_err());
+    assert!("in an hour't time".parse::<RelativeTime>().is_err());
+    // offsets still refer to the original input
+    assert_eq!(
+        "in 2 days' time now"
+            .parse::<RelativeTime>()
+            .unwrap_err()
+            .offset,
+        16
+    );
+}
+
+#[test]
+fn test_parse_bare_weekday() {
+    assert_eq!(
+        "friday".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Weekday(Weekday::Friday)
+    );
+    assert_eq!(
+        "Sunday".parse::<TimeExpression>().unwrap(),
+        TimeExpression::Specific(PointInTime::Relative(RelativeTime::Weekday(
+            Weekday::Sunday
+        )))
+    );
+    assert_eq!(
+        "sunday".parse::<RelativeTime>().unwrap().to_string(),
+        "Sunday"
+    );
+    // `next friday` is unaffected
+    assert_eq!(
+        "next friday".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Next(RelativeTimeUnit::Friday)
+    );
+    // a bare weekday means the next occurrence, strictly after today
+    let friday = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let resolve = |s: &str, now| s.parse::<RelativeTime>().unwrap().resolve(now);
+    assert_eq!(resolve("friday", friday).to_string(), "22/3/2024 at 0:00");
+    assert_eq!(resolve("sunday", friday).to_string(), "17/3/2024 at 0:00");
+    assert_eq!(resolve("friday", friday), resolve("next friday", friday));
+    assert!("fridays".parse::<RelativeTime>().is_err());
+}
+
+#[test]
+fn test_duration_mul() {
+    let parse = |s: &str| s.parse::<Duration>().unwrap();
+    let duration = parse("2 hours, 30 minutes");
+    assert_eq!(duration * 2, parse("4 hours, 60 minutes"));
+    assert_eq!(2 * duration, duration * 2);
+    assert_eq!((duration * 2).normalize(), parse("5 hours"));
+    for factor in [0, 1, 3] {
+        assert!((duration * factor).eq_normalized(&parse(&format!("{} minutes", 150 * factor))));
+    }
+    assert_eq!(
+        parse("3 years, 2 weeks") * 3,
+        Duration::builder().years(9).weeks(6).build()
+    );
+    // saturates rather than overflowing
+    assert_eq!((duration * u64::MAX).hours, Number(u64::MAX));
+    assert_eq!((duration * u64::MAX).minutes, Number(u64::MAX));
+}
+
+#[test]
+fn test_duration_get_set() {
+    let duration = "1 year, 2 months, 3 weeks, 4 days, 5 hours and 6 minutes"
+        .parse::<Duration>()
+        .unwrap();
+    assert_eq!(duration.get(TimeUnit::Days), duration.days);
+    assert_eq!(duration.get(TimeUnit::Years), duration.years);
+    assert_eq!(duration.get(TimeUnit::Minutes), Number(6));
+    let units = [
+        TimeUnit::Minutes,
+        TimeUnit::Hours,
+        TimeUnit::Days,
+        TimeUnit::Weeks,
+        TimeUnit::Months,
+        TimeUnit::Years,
+    ];
+    let mut rebuilt = Duration::builder().build();
+    for unit in units {
+        rebuilt.set(unit, duration.get(unit));
+    }
+    assert_eq!(rebuilt, duration);
+    let mut doubled = duration;
+    for unit in units {
+        doubled.set(unit, duration.get(unit) * Number(2));
+    }
+    assert_eq!(doubled, duration * 2);
+}
+
+#[test]
+fn test_normalized_duration_hash() {
+    use std::collections::HashSet;
+    let parse = |s: &str| s.parse::<Duration>().unwrap();
+    let mut set = HashSet::new();
+    assert!(set.insert(NormalizedDuration::from(parse("1 hour"))));
+    assert!(!set.insert(NormalizedDuration::from(parse("60 minutes"))));
+    assert_eq!(set.len(), 1);
+    // the first spelling inserted is kept
+    assert_eq!(set.iter().next().unwrap().0, parse("1 hour"));
+    assert!(set.insert(parse("61 minutes").into()));
+    assert_eq!(set.len(), 2);
+    // the base type still hashes field by field
+    let exact: HashSet<Duration> = [parse("1 hour"), parse("60 minutes")].into();
+    assert_eq!(exact.len(), 2);
+    assert_eq!(
+        NormalizedDuration::from(parse("2 weeks")),
+        NormalizedDuration::from(parse("1 week, 7 days"))
+    );
+}
+
+#[test]
+fn test_parse_signed_offsets() {
+    let range = "from now to +3 days".parse::<TimeRange>().unwrap();
+    assert_eq!(range, "from now to 3 days from now".parse().unwrap());
+    assert_eq!(range.to_string(), "from now to 3 days from now");
+    let range = "from -2 hours to now".parse::<TimeExpression>().unwrap();
+    assert_eq!(range, "from 2 hours ago to now".parse().unwrap());
+    assert_eq!(range.to_string(), "from 2 hours ago to now");
+    assert_eq!(
+        "+1 week, 2 days"
+            .parse::<PointInTime>()
+            .unwrap()
+            .to_string(),
+        "1 week, 2 days from now"
+    );
+    assert_eq!(
+        "from -1 day".parse::<TimeExpression>().unwrap().to_string(),
+        "from 1 day ago"
+    );
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let TimeExpression::Range(TimeRange(start, _)) = range else {
+        panic!("expected a range");
+    };
+    assert_eq!(start.resolve(now).to_string(), "15/3/2024 at 8:30");
+    // the UTC offset form of `now` is unaffected
+    assert_eq!(
+        "now +09:00".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Named(NamedRelativeTime::NowIn(UtcOffset(9 * 60)))
+    );
+    assert!("from now to + 3".parse::<TimeRange>().is_err());
+    assert!("from now to +-3 days".parse::<TimeRange>().is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_comparisons() {
+    use chrono::{NaiveDate, NaiveDateTime};
+    let date = "20/4/2021".parse::<Date>().unwrap();
+    let naive = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    assert_eq!(date.cmp_naive(&naive(2021, 4, 20)), Some(Ordering::Equal));
+    assert_eq!(date.cmp_naive(&naive(2021, 4, 21)), Some(Ordering::Less));
+    // chronological rather than structural: April 2021 comes before January 2022
+    assert_eq!(date.cmp_naive(&naive(2022, 1, 1)), Some(Ordering::Less));
+    assert_eq!(
+        date.cmp_naive(&naive(2020, 12, 31)),
+        Some(Ordering::Greater)
+    );
+    let invalid = Date(Month::February, DayOfMonth(31), Year(2024));
+    assert_eq!(invalid.cmp_naive(&naive(2024, 3, 2)), None);
+
+    let date_time = "20/4/2021 at 2:30 PM".parse::<DateTime>().unwrap();
+    let naive_dt = |s: &str| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+    let cmp = |dt: DateTime, s| dt.cmp_naive(&naive_dt(s));
+    assert_eq!(cmp(date_time, "2021-04-20 14:30:00"), Some(Ordering::Equal));
+    assert_eq!(cmp(date_time, "2021-04-20 14:30:01"), Some(Ordering::Less));
+    assert_eq!(
+        cmp(date_time, "2021-04-20 14:29:59"),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(cmp(date_time, "2022-01-01 00:00:00"), Some(Ordering::Less));
+    let end_of_day = "20/4/2021 at 24:00".parse::<DateTime>().unwrap();
+    assert_eq!(
+        cmp(end_of_day, "2021-04-21 00:00:00"),
+        Some(Ordering::Equal)
+    );
+    // plain comparisons still infer the parsed type
+    assert_eq!(date_time, "20/4/2021 at 2:30 PM".parse().unwrap());
+}
+
+#[test]
+fn test_parse_attached_am_pm() {
+    assert_eq!(
+        "5:30PM".parse::<Time>().unwrap(),
+        Time(Hour::Hour12(5, AmPm::PM), Minute(30))
+    );
+    assert_eq!(
+        "11:05am".parse::<Time>().unwrap(),
+        Time(Hour::Hour12(11, AmPm::AM), Minute(5))
+    );
+    assert_eq!(
+        "20/4/2021 at 9:15Pm".parse::<DateTime>().unwrap(),
+        "20/4/2021 at 9:15 PM".parse::<DateTime>().unwrap()
+    );
+    // bare hours are accepted where the grammar allows them, e.g. in clock ranges
+    assert_eq!(
+        "from 9AM to 11PM".parse::<TimeRange>().unwrap(),
+        "from 9 AM to 11 PM".parse::<TimeRange>().unwrap()
+    );
+    assert_eq!(
+        TimeExpression::parse_lenient("from 9am to 5pm.").unwrap(),
+        "from 9 AM to 5 PM".parse::<TimeExpression>().unwrap()
+    );
+    // offsets still refer to the original input
+    assert_eq!("5:30PM now".parse::<Time>().unwrap_err().offset, 7);
+    assert!("13:30PM".parse::<Time>().is_err());
+}
+
+#[test]
+fn test_to_absolute() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let range = "from 8789 hours ago to 37 days from now"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert_eq!(
+        range.start_absolute(now),
+        AbsoluteTime::DateTime("15/3/2023 at 5:30".parse().unwrap())
+    );
+    assert_eq!(
+        range.end_absolute(now),
+        AbsoluteTime::DateTime("21/4/2024 at 10:30".parse().unwrap())
+    );
+    // absolute endpoints are returned unchanged
+    let range = "from 1/1/2024 to 2/1/2024 at 9:00"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert_eq!(
+        range.start_absolute(now),
+        AbsoluteTime::Date("1/1/2024".parse().unwrap())
+    );
+    assert_eq!(range.end_absolute(now).to_string(), "2/1/2024 at 9:00");
+    assert_eq!(
+        "tomorrow".parse::<PointInTime>().unwrap().to_absolute(now),
+        AbsoluteTime::DateTime("16/3/2024 at 0:00".parse().unwrap())
+    );
+}
+
+#[test]
+fn test_parse_weekday_after_next() {
+    let parse = |s: &str| s.parse::<RelativeTime>().unwrap();
+    assert_eq!(
+        parse("tuesday after next"),
+        RelativeTime::WeekdayAfterNext(Weekday::Tuesday)
+    );
+    assert_eq!(parse("the Tuesday after next"), parse("tuesday after next"));
+    assert_eq!(
+        parse("the tuesday after next").to_string(),
+        "Tuesday after next"
+    );
+    assert_eq!(
+        "Tuesday after next"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "Tuesday after next"
+    );
+    // from a Monday, the coming Tuesday is skipped in favour of the one after it
+    let monday = "11/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    assert_eq!(monday.0.weekday(), Weekday::Monday);
+    assert_eq!(
+        parse("tuesday").resolve(monday).to_string(),
+        "12/3/2024 at 0:00"
+    );
+    assert_eq!(
+        parse("tuesday after next").resolve(monday).to_string(),
+        "19/3/2024 at 0:00"
+    );
+    assert_eq!(
+        parse("monday after next").resolve(monday).to_string(),
+        "25/3/2024 at 0:00"
+    );
+    // the day-based named times are unaffected
+    assert_eq!(
+        parse("the day after tomorrow"),
+        RelativeTime::Named(NamedRelativeTime::DayAfterTomorrow)
+    );
+    assert!("the tuesday".parse::<RelativeTime>().is_err());
+    assert!("tuesday after".parse::<RelativeTime>().is_err());
+    assert!("the tuesday after last".parse::<RelativeTime>().is_err());
+}
+
+#[test]
+fn test_time_range_touches() {
+    let now = "1/6/2024 at 12:00".parse::<DateTime>().unwrap();
+    let range = |s: &str| s.parse::<TimeRange>().unwrap();
+    let a = range("from 1/6/2024 at 9:00 to 1/6/2024 at 12:00");
+    // touching
+    assert!(a.touches(&range("from 1/6/2024 at 12:00 to 1/6/2024 at 13:00"), now));
+    assert!(a.touches(&range("from now to 2 hours from now"), now));
+    assert!(a.touches(&range("from 1/6/2024 at 8:00 to 1/6/2024 at 9:00"), now));
+    // reversed endpoints are normalized
+    assert!(a.touches(&range("from 1/6/2024 at 13:00 to 1/6/2024 at 12:00"), now));
+    // overlapping
+    assert!(!a.touches(&range("from 1/6/2024 at 11:59 to 1/6/2024 at 13:00"), now));
+    assert!(!a.touches(&a, now));
+    // separated
+    assert!(!a.touches(&range("from 1/6/2024 at 12:01 to 1/6/2024 at 13:00"), now));
+    assert!(!a.touches(&range("from 2/6/2024 to 3/6/2024"), now));
+}
+
+#[test]
+fn test_duration_separators() {
+    let expected = Duration::builder().days(1).hours(2).minutes(3).build();
+    for s in [
+        "1 day and 2 hours and 3 minutes",
+        "1 day, 2 hours and 3 minutes",
+        "1 day, 2 hours, and 3 minutes",
+        "1 day, 2 hours, 3 minutes",
+        "1 day 2 hours 3 minutes",
+        "1 day, and 2 hours 3 minutes",
+        "1 day and 2 hours, 3 minutes",
+    ] {
+        assert_eq!(s.parse::<Duration>().unwrap(), expected, "{s}");
+    }
+    assert_eq!(
+        "1 day and 2 hours and 3 minutes from now"
+            .parse::<RelativeTime>()
+            .unwrap(),
+        "1 day, 2 hours, 3 minutes from now"
+            .parse::<RelativeTime>()
+            .unwrap()
+    );
+    // a trailing `and` is rejected, while a trailing comma is accepted as before
+    assert!("1 day and".parse::<Duration>().is_err());
+    assert!("1 day, and".parse::<Duration>().is_err());
+    assert!("1 day and and 2 hours".parse::<Duration>().is_err());
+    assert_eq!(
+        "2 hours, ago".parse::<RelativeTime>().unwrap().to_string(),
+        "2 hours ago"
+    );
+    assert_eq!(
+        "3 days, from now"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "3 days from now"
+    );
+}
+
+#[test]
+fn test_duration_parse_strict() {
+    let lenient = "2 hours, 3 hours".parse::<Duration>().unwrap();
+    assert_eq!(lenient, Duration::builder().hours(5).build());
+    let err = Duration::parse_strict("2 hours, 3 hours").unwrap_err();
+    assert_eq!(err.offset, 9);
+    assert!(err.message.contains("duplicate `hours`"));
+    assert_eq!(
+        Duration::parse_strict("1 day, 2 hours and 3 minutes").unwrap(),
+        "1 day, 2 hours and 3 minutes".parse::<Duration>().unwrap()
+    );
+    // units are compared after resolving aliases
+    assert_eq!(
+        Duration::parse_strict("1 week and 1 fortnight")
+            .unwrap_err()
+            .offset,
+        11
+    );
+    assert!(Duration::parse_strict("30 minutes and half an hour").is_err());
+    assert!(Duration::parse_strict("1 hour 1 hour").is_err());
+    assert!(Duration::parse_strict("1 hour 1 minute").is_ok());
+}
+
+#[test]
+fn test_time_duration_boundary_errors() {
+    const MESSAGE: &str = "expected a time, found a duration";
+    let err = "at 5h tomorrow".parse::<TimeExpression>().unwrap_err();
+    assert_eq!(err.message, MESSAGE);
+    assert_eq!(err.offset, 3);
+    let err = "tomorrow at 5h".parse::<RelativeTime>().unwrap_err();
+    assert_eq!(err.message, MESSAGE);
+    assert_eq!(err.offset, 12);
+    let err = "at 5 hours tomorrow".parse::<TimeExpression>().unwrap_err();
+    assert_eq!(err.message, MESSAGE);
+    assert_eq!(err.offset, 3);
+    assert_eq!(
+        "at 5hrs tomorrow"
+            .parse::<TimeExpression>()
+            .unwrap_err()
+            .message,
+        MESSAGE
+    );
+    assert_eq!(
+        "at 5:30h tomorrow"
+            .parse::<TimeExpression>()
+            .unwrap_err()
+            .message,
+        MESSAGE
+    );
+    assert_eq!(
+        "5/6/2024 at 5h".parse::<DateTime>().unwrap_err().message,
+        MESSAGE
+    );
+    // clock ranges, where 12-hour times without minutes are accepted
+    let err = "from 5h to 6 PM".parse::<TimeRange>().unwrap_err();
+    assert_eq!(err.message, MESSAGE);
+    assert_eq!(err.offset, 5);
+    assert_eq!(
+        "from 5 PM to 6h".parse::<TimeRange>().unwrap_err().offset,
+        13
+    );
+    // valid times and durations are unaffected
+    assert!("tomorrow at 5:30 PM".parse::<RelativeTime>().is_ok());
+    assert!("from 5 to 6 PM".parse::<TimeRange>().is_ok());
+    assert!("5 hours from now".parse::<RelativeTime>().is_ok());
+    assert!(parse2::<Time>(quote!(5h)).is_err());
+}
+
+#[test]
+fn test_time_expression_bake() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let later = "2/9/2031 at 18:45".parse::<DateTime>().unwrap();
+    let expr = "3 days ago".parse::<TimeExpression>().unwrap();
+    assert!(!expr.is_absolute());
+    let baked = expr.bake(now);
+    assert!(baked.is_absolute());
+    assert_eq!(baked.to_string(), "12/3/2024 at 10:30");
+    assert_eq!(baked.temporal_position(now), Some(TemporalPosition::Past));
+    let TimeExpression::Specific(point) = baked else {
+        panic!("expected a point in time");
+    };
+    assert_eq!(point.resolve(now), point.resolve(later));
+    let point_now = "3 days ago".parse::<PointInTime>().unwrap().resolve(now);
+    assert_eq!(point.resolve(later), point_now);
+    // already-absolute parts are left untouched
+    for s in [
+        "1/4/2024",
+        "from 1/1/2024 to 15/1/2024",
+        "3 days",
+        "all day 5/6/2024",
+        "every 2 weeks starting 1/1/2024",
+    ] {
+        let expr = s.parse::<TimeExpression>().unwrap();
+        assert!(expr.is_absolute(), "{s}");
+        assert_eq!(expr.bake(now), expr);
+    }
+    let expr = "until next tuesday".parse::<TimeExpression>().unwrap();
+    assert_eq!(expr.bake(now).to_string(), "to 19/3/2024 at 0:00");
+    let expr = "every 2 weeks".parse::<TimeExpression>().unwrap();
+    assert!(!expr.is_absolute());
+    let TimeExpression::Recurrence(baked) = expr.bake(now) else {
+        panic!("expected a recurrence");
+    };
+    assert_eq!(baked.next_after(now).to_string(), "29/3/2024 at 10:30");
+    assert_eq!(
+        baked.next_after(now + Duration::builder().days(1).build()),
+        baked.next_after(now)
+    );
+}
+
+#[test]
+fn test_duration_until_and_span() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let range = "from 1/1/2023 to 15/1/2023".parse::<TimeRange>().unwrap();
+    assert!(range.span(now).eq_normalized(&"14 days".parse().unwrap()));
+    let start = "31/12/2023 at 10:15 PM".parse::<AbsoluteTime>().unwrap();
+    let end = "1/1/2024 at 1:45 AM".parse::<AbsoluteTime>().unwrap();
+    assert_eq!(
+        start.duration_until(&end).to_string(),
+        "3 hours, 30 minutes"
+    );
+    assert!(end.duration_until(&start).is_zero());
+    assert!(start.duration_until(&start).is_zero());
+    // leap days are counted
+    let start = "28/2/2024".parse::<AbsoluteTime>().unwrap();
+    let end = "1/3/2024".parse::<AbsoluteTime>().unwrap();
+    assert_eq!(start.duration_until(&end).to_string(), "2 days");
+    let range = "from 1/3/2024 to 28/2/2024".parse::<TimeRange>().unwrap();
+    assert_eq!(range.span(now).to_string(), "2 days");
+    let range = "from yesterday to 5 hours from now"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert_eq!(range.span(now).to_string(), "1 day, 15 hours, 30 minutes");
+}
+
+#[test]
+fn test_named_at_round_trip() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let parsed = "tomorrow at 9:30 AM".parse::<RelativeTime>().unwrap();
+    assert_eq!(
+        parsed,
+        RelativeTime::NamedAt(
+            NamedRelativeTime::Tomorrow,
+            Time(Hour::Hour12(9, AmPm::AM), Minute(30)).into()
+        )
+    );
+    assert_eq!(parsed.to_string(), "tomorrow at 9:30 AM");
+    assert_eq!(parsed.resolve(now).to_string(), "16/3/2024 at 9:30 AM");
+    let parsed = "the day after tomorrow at noon"
+        .parse::<RelativeTime>()
+        .unwrap();
+    assert_eq!(
+        parsed,
+        RelativeTime::NamedAt(
+            NamedRelativeTime::DayAfterTomorrow,
+            Time(Hour::Hour24(12), Minute(0)).into()
+        )
+    );
+    assert_eq!(parsed.resolve(now).to_string(), "17/3/2024 at 12:00");
+    assert_eq!(parsed.to_string().parse::<RelativeTime>().unwrap(), parsed);
+    let parsed = "yesterday at 5pm".parse::<TimeExpression>().unwrap();
+    assert_eq!(parsed.to_string(), "yesterday at 5:00 PM");
+    assert_eq!(
+        parsed.to_string().parse::<TimeExpression>().unwrap(),
+        parsed
+    );
+    // hours with `AM` / `PM` but no minutes are accepted wherever a time is
+    assert_eq!(
+        "at 11 AM tomorrow"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .to_string(),
+        "tomorrow at 11:00 AM"
+    );
+    assert_eq!(
+        "5/6/2024 at 5 PM".parse::<AbsoluteTime>().unwrap(),
+        "5/6/2024 at 5:00 PM".parse::<AbsoluteTime>().unwrap()
+    );
+    assert_eq!(
+        "5/6/2024 5 PM".parse::<PointInTime>().unwrap().to_string(),
+        "5/6/2024 at 5:00 PM"
+    );
+    assert!("tomorrow at 13 PM".parse::<RelativeTime>().is_err());
+    assert!("tomorrow at 5".parse::<RelativeTime>().is_err());
+}
+
+#[test]
+fn test_number_checked_and_saturating_ops() {
+    assert_eq!(Number(1).saturating_sub(Number(5)), Number(0));
+    assert_eq!(Number(5).saturating_sub(Number(1)), Number(4));
+    assert_eq!(Number(1).checked_sub(Number(5)), None);
+    assert_eq!(Number(5).checked_sub(Number(5)), Some(Number(0)));
+    assert_eq!(Number(u64::MAX).saturating_add(Number(1)), Number(u64::MAX));
+    assert_eq!(Number(u64::MAX).checked_add(Number(1)), None);
+    assert_eq!(Number(2).checked_add(Number(3)), Some(Number(5)));
+    let short = "30 minutes".parse::<Duration>().unwrap();
+    let long = "1 hour".parse::<Duration>().unwrap();
+    assert_eq!(short - long, Duration::builder().minutes(30).build());
+}
+
+#[test]
+fn test_parse_signed_duration() {
+    let parsed = "-3 days".parse::<SignedDuration>().unwrap();
+    assert_eq!(
+        parsed,
+        SignedDuration {
+            negative: true,
+            duration: Duration::builder().days(3).build(),
+        }
+    );
+    assert_eq!(parsed.to_string(), "-3 days");
+    assert_ne!(parsed, "3 days".parse::<SignedDuration>().unwrap());
+    let parsed = "minus 2 hours".parse::<SignedDuration>().unwrap();
+    assert!(parsed.negative);
+    assert_eq!(parsed.to_string(), "-2 hours");
+    assert_eq!(
+        parsed.to_string().parse::<SignedDuration>().unwrap(),
+        parsed
+    );
+    let parsed = parse2::<SignedDuration>(quote!(MINUS 1 week, 2 days)).unwrap();
+    assert_eq!(parsed.to_string(), "-1 week, 2 days");
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    assert_eq!(
+        (now + "-3 days".parse::<SignedDuration>().unwrap()).to_string(),
+        "12/3/2024 at 10:30"
+    );
+    assert_eq!(
+        (now + "2 hours".parse::<SignedDuration>().unwrap()).to_string(),
+        "15/3/2024 at 12:30"
+    );
+    // plain durations remain unsigned
+    assert!("-3 days".parse::<Duration>().is_err());
+    assert!("minus 2 hours".parse::<Duration>().is_err());
+    assert!("plus 2 hours".parse::<SignedDuration>().is_err());
+    assert!("- -2 hours".parse::<SignedDuration>().is_err());
+}
+
+#[cfg(feature = "std-clock")]
+#[test]
+fn test_resolve_against_system_clock() {
+    let local_now = || -> DateTime { chrono::Local::now().naive_local().try_into().unwrap() };
+    let before = local_now();
+    let resolved = "now".parse::<RelativeTime>().unwrap().resolve_now();
+    let after = local_now();
+    // the clock may tick over to the next minute in between
+    assert!(resolved == before || resolved == after);
+    let point = "1 hour from now".parse::<PointInTime>().unwrap();
+    let AbsoluteTime::DateTime(resolved) = point.to_absolute_now() else {
+        panic!("expected a date time");
+    };
+    let hour = Duration::builder().hours(1).build();
+    assert!(resolved == after + hour || resolved == local_now() + hour);
+    let point = "1/1/2024".parse::<PointInTime>().unwrap();
+    assert_eq!(point.to_absolute_now(), "1/1/2024".parse().unwrap());
+}
+
+#[test]
+fn test_parse_a_duration_ago() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    assert_eq!(
+        "a week ago".parse::<RelativeTime>().unwrap(),
+        RelativeTime::Directional {
+            duration: Duration::builder().weeks(1).build(),
+            dir: TimeDirection::Ago,
+        }
+    );
+    assert_eq!(
+        "an hour from now"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .to_string(),
+        "1 hour from now"
+    );
+    let parsed = "a year ago today".parse::<RelativeTime>().unwrap();
+    assert_eq!(
+        parsed,
+        RelativeTime::Directional {
+            duration: Duration::builder().years(1).build(),
+            dir: TimeDirection::BeforeNamed(NamedRelativeTime::Today),
+        }
+    );
+    assert_eq!(parsed.to_string(), "1 year before today");
+    assert_eq!(parsed.resolve(now).to_string(), "15/3/2023 at 0:00");
+    let parsed = "2 weeks ago tomorrow".parse::<TimeExpression>().unwrap();
+    assert_eq!(parsed.to_string(), "2 weeks before tomorrow");
+    // `ago` and `from now` only attach to durations
+    assert!("tomorrow ago".parse::<TimeExpression>().is_err());
+    assert!("yesterday from now".parse::<TimeExpression>().is_err());
+    assert!("a week ago yesterday".parse::<TimeExpression>().is_err());
+}
+
+#[test]
+fn test_duration_to_words() {
+    let words = |s: &str| s.parse::<Duration>().unwrap().to_words();
+    assert_eq!(words("1 hour"), "one hour");
+    assert_eq!(words("2 hours, 30 minutes"), "two hours and thirty minutes");
+    assert_eq!(
+        words("1 year, 3 months and 1 day"),
+        "one year, three months and one day"
+    );
+    assert_eq!(words("13 weeks"), "thirteen weeks");
+    assert_eq!(words("45 minutes"), "forty-five minutes");
+    assert_eq!(words("100 days"), "one hundred days");
+    assert_eq!(
+        words("1234 hours"),
+        "one thousand two hundred thirty-four hours"
+    );
+    assert_eq!(words("1000001 minutes"), "one million one minutes");
+    assert_eq!(Duration::builder().build().to_words(), "zero minutes");
+    assert_eq!(
+        Duration::builder().years(u64::MAX).build().to_words(),
+        "eighteen quintillion four hundred forty-six quadrillion seven hundred forty-four \
+         trillion seventy-three billion seven hundred nine million five hundred fifty-one \
+         thousand six hundred fifteen years"
+    );
+}
+
+#[test]
+fn test_enum_values_and_aliases() {
+    assert_eq!(TimeUnit::all().len(), 6);
+    assert_eq!(Month::all().len(), 12);
+    assert_eq!(RelativeTimeUnit::all().len(), 10);
+    assert_eq!(TimeUnit::all_aliases().len(), 17);
+    assert_eq!(Month::all_aliases().len(), 23);
+    assert_eq!(RelativeTimeUnit::all_aliases().len(), 10);
+    for (alias, unit) in TimeUnit::all_aliases() {
+        assert_eq!(alias.parse::<TimeUnit>().unwrap(), *unit);
+        assert_eq!(alias.to_uppercase().parse::<TimeUnit>().unwrap(), *unit);
+    }
+    for (alias, month) in Month::all_aliases() {
+        assert_eq!(alias.parse::<Month>().unwrap(), *month);
+    }
+    for (alias, unit) in RelativeTimeUnit::all_aliases() {
+        assert_eq!(alias.parse::<RelativeTimeUnit>().unwrap(), *unit);
+    }
+    // every value has at least one alias, and each is listed once
+    for unit in TimeUnit::all() {
+        assert!(TimeUnit::all_aliases().iter().any(|(_, u)| u == unit));
+        assert_eq!(TimeUnit::all().iter().filter(|u| *u == unit).count(), 1);
+    }
+    for (i, month) in Month::all().iter().enumerate() {
+        assert_eq!(u8::from(*month) as usize, i + 1);
+        assert!(Month::all_aliases().iter().any(|(_, m)| m == month));
+    }
+    for unit in RelativeTimeUnit::all() {
+        assert!(RelativeTimeUnit::all_aliases()
+            .iter()
+            .any(|(_, u)| u == unit));
+    }
+}
+
+#[test]
+fn test_parse_with_year_pivot() {
+    let pivoted = |s: &str| {
+        Date::parse_with_year_pivot(s, Date::POSIX_YEAR_PIVOT)
+            .unwrap()
+            .to_string()
+    };
+    assert_eq!(pivoted("20/4/91"), "20/4/1991");
+    assert_eq!(pivoted("20/4/20"), "20/4/2020");
+    assert_eq!(pivoted("1/1/68"), "1/1/2068");
+    assert_eq!(pivoted("1/1/69"), "1/1/1969");
+    assert_eq!(pivoted("1/1/00"), "1/1/2000");
+    assert_eq!(pivoted("5/6/07"), "5/6/2007");
+    assert_eq!(pivoted("5 June 99"), "5/6/1999");
+    // other lengths are left alone
+    assert_eq!(pivoted("5/6/2024"), "5/6/2024");
+    assert_eq!(pivoted("5/6/7"), "5/6/7");
+    assert_eq!(pivoted("5/6/091"), "5/6/91");
+    assert_eq!(pivoted("2024/6/5"), "5/6/2024");
+    assert_eq!(
+        Date::parse_with_year_pivot("20/4/50", 40)
+            .unwrap()
+            .to_string(),
+        "20/4/1950"
+    );
+    // the default behavior is unchanged
+    assert_eq!("20/4/91".parse::<Date>().unwrap().2, Year(91));
+    let err = Date::parse_with_year_pivot("20/4/91 extra", 69).unwrap_err();
+    assert_eq!(err.offset, "20/4/91 ".len());
+    assert_eq!(err.message, "unexpected token");
+}
+
+#[test]
+fn test_date_cmp_chronological() {
+    let date = |s: &str| s.parse::<Date>().unwrap();
+    let (new_year, new_years_eve) = (date("1/1/2025"), date("31/12/2024"));
+    assert_eq!(
+        new_year.cmp_chronological(&new_years_eve),
+        std::cmp::Ordering::Greater
+    );
+    assert_eq!(new_year.cmp(&new_years_eve), std::cmp::Ordering::Less);
+    assert_eq!(
+        date("5/6/2024").cmp_chronological(&date("5 June 2024")),
+        std::cmp::Ordering::Equal
+    );
+    let mut dates = ["3/2/2024", "1/3/2023", "2/2/2024", "31/12/2023"].map(date);
+    dates.sort_by(Date::cmp_chronological);
+    assert_eq!(
+        dates.map(|d| d.to_string()),
+        ["1/3/2023", "31/12/2023", "2/2/2024", "3/2/2024"]
+    );
+    // agrees with ordering by day count
+    for a in dates {
+        for b in dates {
+            assert_eq!(a.cmp_chronological(&b), a.days().cmp(&b.days()));
+        }
+    }
+}
+
+#[test]
+fn test_date_time_cmp_chronological() {
+    use std::cmp::Ordering::*;
+    let dt = |s: &str| s.parse::<DateTime>().unwrap();
+    let (early, late) = (dt("1/1/2025 at 1:00 AM"), dt("1/1/2025 at 13:00"));
+    assert_eq!(early.cmp_chronological(&late), Less);
+    assert_eq!(late.cmp_chronological(&early), Greater);
+    // the derived ordering puts every 12-hour time first
+    assert_eq!(dt("1/1/2025 at 11:00 PM").cmp(&late), Less);
+    assert_eq!(dt("1/1/2025 at 11:00 PM").cmp_chronological(&late), Greater);
+    assert_eq!(
+        dt("1/1/2025 at 12:30 AM").cmp_chronological(&dt("1/1/2025 at 0:30")),
+        Equal
+    );
+    assert_eq!(
+        dt("1/1/2025 at 0:00").cmp_chronological(&dt("31/12/2024 at 23:59")),
+        Greater
+    );
+    assert_eq!(
+        dt("1/1/2025 at 24:00").cmp_chronological(&dt("1/1/2025 at 11:59 PM")),
+        Greater
+    );
+    let mut date_times = [
+        "2/1/2025 at 9:00 AM",
+        "1/1/2025 at 13:00",
+        "1/1/2025 at 1:00 AM",
+        "31/12/2024 at 23:00",
+    ]
+    .map(dt);
+    date_times.sort_by(DateTime::cmp_chronological);
+    assert_eq!(
+        date_times.map(|d| d.to_string()),
+        [
+            "31/12/2024 at 23:00",
+            "1/1/2025 at 1:00 AM",
+            "1/1/2025 at 13:00",
+            "2/1/2025 at 9:00 AM"
+        ]
+    );
+}
+
+/// A tiny deterministic xorshift generator, used to produce arbitrary ASTs for property-style
+/// tests without pulling in a property testing framework.
+struct Arbitrary(u64);
+
+impl Arbitrary {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `range`.
+    fn range(&mut self, range: std::ops::RangeInclusive<u64>) -> u64 {
+        range.start() + self.next() % (range.end() - range.start() + 1)
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.range(0..=items.len() as u64 - 1) as usize]
+    }
+
+    fn duration(&mut self) -> Duration {
+        let mut duration = Duration::builder().build();
+        while duration.is_zero() {
+            for unit in TimeUnit::all() {
+                if self.range(0..=2) == 0 {
+                    duration.set(*unit, Number(self.range(1..=500)));
+                }
+            }
+        }
+        duration
+    }
+
+    fn date(&mut self) -> Date {
+        Date(
+            self.pick(Month::all()),
+            DayOfMonth(self.range(1..=28) as u8),
+            Year(self.range(1..=9999) as u16),
+        )
+    }
+
+    fn time(&mut self) -> Time {
+        let minute = Minute(self.range(0..=59) as u8);
+        match self.range(0..=2) {
+            0 => Time(
+                Hour::Hour12(self.range(1..=12) as u8, self.pick(&[AmPm::AM, AmPm::PM])),
+                minute,
+            ),
+            1 => Time(Hour::Hour24(self.range(0..=23) as u8), minute),
+            _ => Time(Hour::Hour24(self.range(0..=24) as u8), Minute(0)),
+        }
+    }
+
+    fn qualified_time(&mut self) -> QualifiedTime {
+        let precision = self.pick(&[Precision::Unqualified, Precision::Exact]);
+        QualifiedTime(self.time(), precision)
+    }
+
+    fn absolute(&mut self) -> AbsoluteTime {
+        match self.range(0..=1) {
+            0 => AbsoluteTime::Date(self.date()),
+            _ => AbsoluteTime::DateTime(DateTime(self.date(), self.time())),
+        }
+    }
+
+    fn named(&mut self) -> NamedRelativeTime {
+        use NamedRelativeTime::*;
+        match self.range(0..=1) {
+            0 => NowIn(UtcOffset(self.range(0..=28) as i16 * 60 - 14 * 60)),
+            _ => self.pick(&[
+                Now,
+                Today,
+                Tomorrow,
+                Yesterday,
+                DayAfterTomorrow,
+                DayBeforeYesterday,
+                Midday,
+                EndOfDay,
+                EndOfWeek,
+                EndOfMonth,
+                EndOfYear,
+            ]),
+        }
+    }
+
+    fn weekday(&mut self) -> Weekday {
+        Weekday::from_iso_number(self.range(1..=7) as u8).unwrap()
+    }
+
+    fn direction(&mut self) -> TimeDirection {
+        let unit = self.pick(RelativeTimeUnit::all());
+        match self.range(0..=Arbitrary::DIRECTION_VARIANTS - 1) {
+            0 => TimeDirection::AfterAbsolute(self.absolute()),
+            1 => TimeDirection::BeforeAbsolute(self.absolute()),
+            2 => TimeDirection::AfterNamed(self.named()),
+            3 => TimeDirection::BeforeNamed(self.named()),
+            4 => TimeDirection::BeforeNext(unit),
+            5 => TimeDirection::BeforeLast(unit),
+            6 => TimeDirection::AfterNext(unit),
+            7 => TimeDirection::AfterLast(unit),
+            8 => TimeDirection::Ago,
+            9 => TimeDirection::FromNow,
+            _ => unreachable!("no generator for variant"),
+        }
+    }
+
+    fn relative(&mut self) -> RelativeTime {
+        let unit = self.pick(RelativeTimeUnit::all());
+        match self.range(0..=Arbitrary::RELATIVE_VARIANTS - 1) {
+            0 => RelativeTime::Directional {
+                duration: self.duration(),
+                dir: self.direction(),
+            },
+            1 => RelativeTime::Named(self.named()),
+            2 => RelativeTime::Next(unit),
+            3 => RelativeTime::Last(unit),
+            4 => RelativeTime::ThisTimeNamed(self.named()),
+            5 => RelativeTime::ThisTimeNext(unit),
+            6 => RelativeTime::ThisTimeLast(unit),
+            7 => RelativeTime::NamedAt(self.named(), self.qualified_time()),
+            8 => RelativeTime::This(unit),
+            9 => RelativeTime::Weekday(self.weekday()),
+            10 => RelativeTime::WeekdayAfterNext(self.weekday()),
+            _ => unreachable!("no generator for variant"),
+        }
+    }
+
+    fn point(&mut self) -> PointInTime {
+        match self.range(0..=1) {
+            0 => PointInTime::Absolute(self.absolute()),
+            _ => PointInTime::Relative(self.relative()),
+        }
+    }
+
+    fn expression(&mut self) -> TimeExpression {
+        match self.range(0..=Arbitrary::EXPRESSION_VARIANTS - 1) {
+            0 => TimeExpression::Specific(self.point()),
+            1 => TimeExpression::Range(TimeRange(self.point(), self.point())),
+            2 => TimeExpression::OpenRange(match self.range(0..=1) {
+                0 => OpenTimeRange::From(self.point()),
+                _ => OpenTimeRange::To(self.point()),
+            }),
+            3 => TimeExpression::Duration(self.duration()),
+            4 => TimeExpression::AllDay(self.date()),
+            5 => TimeExpression::Recurrence(Recurrence {
+                interval: self.duration(),
+                start: (self.range(0..=1) == 0).then(|| self.point()),
+            }),
+            _ => unreachable!("no generator for variant"),
+        }
+    }
+}
+
+// The generators above pick variants by index. The exhaustive matches below map each variant
+// back to its index, so adding a variant fails to build until it is given one, and
+// `test_arbitrary_covers_all_variants` then fails until the variant count (and with it, the
+// generator) is updated.
+impl Arbitrary {
+    const DIRECTION_VARIANTS: u64 = 10;
+    const RELATIVE_VARIANTS: u64 = 11;
+    const EXPRESSION_VARIANTS: u64 = 6;
+
+    fn direction_index(dir: &TimeDirection) -> u64 {
+        match dir {
+            TimeDirection::AfterAbsolute(_) => 0,
+            TimeDirection::BeforeAbsolute(_) => 1,
+            TimeDirection::AfterNamed(_) => 2,
+            TimeDirection::BeforeNamed(_) => 3,
+            TimeDirection::BeforeNext(_) => 4,
+            TimeDirection::BeforeLast(_) => 5,
+            TimeDirection::AfterNext(_) => 6,
+            TimeDirection::AfterLast(_) => 7,
+            TimeDirection::Ago => 8,
+            TimeDirection::FromNow => 9,
+        }
+    }
+
+    fn relative_index(relative: &RelativeTime) -> u64 {
+        match relative {
+            RelativeTime::Directional { .. } => 0,
+            RelativeTime::Named(_) => 1,
+            RelativeTime::Next(_) => 2,
+            RelativeTime::Last(_) => 3,
+            RelativeTime::ThisTimeNamed(_) => 4,
+            RelativeTime::ThisTimeNext(_) => 5,
+            RelativeTime::ThisTimeLast(_) => 6,
+            RelativeTime::NamedAt(..) => 7,
+            RelativeTime::This(_) => 8,
+            RelativeTime::Weekday(_) => 9,
+            RelativeTime::WeekdayAfterNext(_) => 10,
+        }
+    }
+
+    fn expression_index(expr: &TimeExpression) -> u64 {
+        match expr {
+            TimeExpression::Specific(_) => 0,
+            TimeExpression::Range(_) => 1,
+            TimeExpression::OpenRange(_) => 2,
+            TimeExpression::Duration(_) => 3,
+            TimeExpression::AllDay(_) => 4,
+            TimeExpression::Recurrence(_) => 5,
+        }
+    }
+}
+
+#[test]
+fn test_arbitrary_expressions_round_trip() {
+    let mut arbitrary = Arbitrary(0x5eed_cafe_f00d_d00d);
+    for _ in 0..5000 {
+        assert_roundtrip(&arbitrary.expression());
+    }
+}
+
+#[test]
+fn test_arbitrary_covers_all_variants() {
+    use std::collections::BTreeSet;
+    let mut arbitrary = Arbitrary(0x0c0f_fee0_5eed);
+    let (mut directions, mut relatives, mut expressions) =
+        (BTreeSet::new(), BTreeSet::new(), BTreeSet::new());
+    for _ in 0..1000 {
+        directions.insert(Arbitrary::direction_index(&arbitrary.direction()));
+        relatives.insert(Arbitrary::relative_index(&arbitrary.relative()));
+        expressions.insert(Arbitrary::expression_index(&arbitrary.expression()));
+    }
+    let all = |count| (0..count).collect::<BTreeSet<u64>>();
+    assert_eq!(directions, all(Arbitrary::DIRECTION_VARIANTS));
+    assert_eq!(relatives, all(Arbitrary::RELATIVE_VARIANTS));
+    assert_eq!(expressions, all(Arbitrary::EXPRESSION_VARIANTS));
+}
+
+/// A value whose [Display] impl deliberately loses information, so that it does not survive
+/// a round trip.
+#[derive(PartialEq, Debug)]
+struct Lossy(u8);
+
+impl Display for Lossy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0 / 10 * 10)
+    }
+}
+
+impl FromStr for Lossy {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Lossy(s.parse()?))
+    }
+}
+
+#[test]
+fn test_assert_roundtrip_accepts_symmetric() {
+    assert_roundtrip(&Lossy(30));
+}
+
+#[test]
+#[should_panic(expected = "`40` re-parsed to a different value")]
+fn test_assert_roundtrip_detects_asymmetry() {
+    assert_roundtrip(&Lossy(42));
+}
+
+#[test]
+fn test_parse_between_and() {
+    let date = |s: &str| PointInTime::Absolute(AbsoluteTime::Date(s.parse().unwrap()));
+    let range = "between 1/1/2023 and 15/1/2023"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert_eq!(range, TimeRange(date("1/1/2023"), date("15/1/2023")));
+    assert_eq!(range.to_string(), "from 1/1/2023 to 15/1/2023");
+    assert_eq!(
+        "between 1/1/2023 and 15/1/2023"
+            .parse::<TimeExpression>()
+            .unwrap(),
+        TimeExpression::Range(range)
+    );
+    assert_eq!(
+        parse2::<TimeExpression>(quote!(Between 1/1/2023 AND 15/1/2023)).unwrap(),
+        TimeExpression::Range(range)
+    );
+    // `and` inside a duration endpoint belongs to the duration
+    let range = "between 2 days and 3 hours ago and 1 week and 1 day from now"
+        .parse::<TimeRange>()
+        .unwrap();
+    assert_eq!(
+        range.to_string(),
+        "from 2 days, 3 hours ago to 1 week, 1 day from now"
+    );
+    assert_eq!(
+        "between now and tomorrow at noon"
+            .parse::<TimeExpression>()
+            .unwrap()
+            .to_string(),
+        "from now to tomorrow at 12:00"
+    );
+    assert!("between 1/1/2023 to 15/1/2023"
+        .parse::<TimeRange>()
+        .is_err());
+    assert!("between 1/1/2023".parse::<TimeExpression>().is_err());
+    assert!("between 1/1/2023 and".parse::<TimeExpression>().is_err());
+}
+
+#[test]
+fn test_to_12h_string() {
+    let time = |s: &str| s.parse::<Time>().unwrap();
+    assert_eq!(time("13:00").to_12h_string(), "1:00 PM");
+    assert_eq!(time("0:00").to_12h_string(), "12:00 AM");
+    assert_eq!(time("12:00").to_12h_string(), "12:00 PM");
+    assert_eq!(time("noon").to_12h_string(), "12:00 PM");
+    assert_eq!(time("midnight").to_12h_string(), "12:00 AM");
+    assert_eq!(time("11:59").to_12h_string(), "11:59 AM");
+    assert_eq!(time("23:07").to_12h_string(), "11:07 PM");
+    assert_eq!(time("24:00").to_12h_string(), "12:00 AM");
+    assert_eq!(time("5:30 PM").to_12h_string(), "5:30 PM");
+    // the default display is unchanged
+    assert_eq!(time("13:00").to_string(), "13:00");
+    let date_time = |s: &str| s.parse::<DateTime>().unwrap();
+    assert_eq!(
+        date_time("5/6/2024 at 0:15").to_12h_string(),
+        "5/6/2024 at 12:15 AM"
+    );
+    assert_eq!(
+        date_time("31/12/2024 at 24:00").to_12h_string(),
+        "1/1/2025 at 12:00 AM"
+    );
+    assert_eq!(
+        date_time("5/6/2024 at 1:05 PM").to_12h_string(),
+        "5/6/2024 at 1:05 PM"
+    );
+}
+
+#[test]
+fn test_parse_all() {
+    for s in [
+        "3 days",
+        "3 days ago",
+        "from now to tomorrow",
+        "from noon tomorrow",
+        "until 5/6/2024",
+        "all day 5/6/2024",
+        "every 2 weeks starting 1/1/2024",
+        "5/6/2024 from noon to midnight",
+        "between 1/1/2023 and 15/1/2023",
+    ] {
+        assert_eq!(
+            TimeExpression::parse_all(s),
+            vec![s.parse::<TimeExpression>().unwrap()],
+            "{s}"
+        );
+    }
+    assert!(TimeExpression::parse_all("3 dayz").is_empty());
+    assert!(TimeExpression::parse_all("").is_empty());
+    assert_eq!(
+        TimeExpression::parse_all("tomorrow at 5PM"),
+        vec!["tomorrow at 5 PM".parse::<TimeExpression>().unwrap()]
+    );
+    // no generated expression has more than one interpretation
+    let mut arbitrary = Arbitrary(0x0a11_0f7e_5e5e);
+    for _ in 0..1000 {
+        let expr = arbitrary.expression();
+        assert_eq!(TimeExpression::parse_all(&expr.to_string()), vec![expr]);
+    }
+}
+
+#[test]
+fn test_parse_quarters_and_weeks() {
+    let range = |s: &str| match s.parse::<TimeExpression>().unwrap() {
+        TimeExpression::Range(range) => range.to_string(),
+        other => panic!("expected a range, found {other:?}"),
+    };
+    assert_eq!(
+        range("Q1 2024"),
+        "from 1/1/2024 at 0:00 to 31/3/2024 at 23:59"
+    );
+    assert_eq!(
+        range("q2 2024"),
+        "from 1/4/2024 at 0:00 to 30/6/2024 at 23:59"
+    );
+    assert_eq!(
+        range("Q3 2023"),
+        "from 1/7/2023 at 0:00 to 30/9/2023 at 23:59"
+    );
+    assert_eq!(
+        range("Q4 2024"),
+        "from 1/10/2024 at 0:00 to 31/12/2024 at 23:59"
+    );
+    // 1/1/2024 is a Monday
+    assert_eq!(
+        range("week 1 of 2024"),
+        "from 1/1/2024 at 0:00 to 7/1/2024 at 23:59"
+    );
+    assert_eq!(
+        range("week 3 of 2024"),
+        "from 15/1/2024 at 0:00 to 21/1/2024 at 23:59"
+    );
+    // ISO week 1 of 2021 starts on 4/1/2021, and 2020 has 53 weeks
+    assert_eq!(
+        range("week 1 of 2021"),
+        "from 4/1/2021 at 0:00 to 10/1/2021 at 23:59"
+    );
+    assert_eq!(
+        range("Week 53 of 2020"),
+        "from 28/12/2020 at 0:00 to 3/1/2021 at 23:59"
+    );
+    // week 1 of 2025 starts in 2024
+    assert_eq!(
+        range("week 1 of 2025"),
+        "from 30/12/2024 at 0:00 to 5/1/2025 at 23:59"
+    );
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let q1 = "Q1 2024".parse::<TimeRange>().unwrap();
+    assert!(q1.contains(&"31/3/2024 at 23:59".parse().unwrap(), now));
+    assert!(!q1.contains(&"1/4/2024".parse().unwrap(), now));
+    assert_eq!(
+        "week 53 of 2024".parse::<TimeRange>().unwrap_err().message,
+        "week must be between 1 and 52 (inclusive)"
+    );
+    assert!("week 0 of 2024".parse::<TimeExpression>().is_err());
+    assert_eq!(
+        "Q5 2024".parse::<TimeExpression>().unwrap_err().message,
+        "quarter must be between 1 and 4 (inclusive)"
+    );
+    assert!("Q1".parse::<TimeExpression>().is_err());
+    assert!("week 3 2024".parse::<TimeExpression>().is_err());
+    // other uses of `week` are unaffected
+    assert!("next week".parse::<TimeExpression>().is_ok());
+    assert!("1 week".parse::<TimeExpression>().is_ok());
+}
+
+#[test]
+fn test_resolve_config_week_start() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap(); // a Friday
+    let monday = ResolveConfig::default();
+    let sunday = ResolveConfig {
+        week_start: Weekday::Sunday,
+    };
+    assert_eq!(monday.week_start, Weekday::Monday);
+    let resolve = |s: &str, config| {
+        s.parse::<RelativeTime>()
+            .unwrap()
+            .resolve_with(now, config)
+            .to_string()
+    };
+    assert_eq!(resolve("next week", monday), "18/3/2024 at 0:00");
+    assert_eq!(resolve("next week", sunday), "17/3/2024 at 0:00");
+    assert_eq!(resolve("last week", monday), "4/3/2024 at 0:00");
+    assert_eq!(resolve("last week", sunday), "3/3/2024 at 0:00");
+    assert_eq!(resolve("this week", sunday), "10/3/2024 at 0:00");
+    assert_eq!(resolve("this sunday", monday), "17/3/2024 at 0:00");
+    assert_eq!(resolve("this sunday", sunday), "10/3/2024 at 0:00");
+    assert_eq!(resolve("this friday", sunday), "15/3/2024 at 0:00");
+    assert_eq!(resolve("end of week", monday), "17/3/2024 at 23:59");
+    assert_eq!(resolve("end of week", sunday), "16/3/2024 at 23:59");
+    // weekdays relative to today don't depend on the start of the week
+    assert_eq!(
+        resolve("next sunday", monday),
+        resolve("next sunday", sunday)
+    );
+    let point = "next week".parse::<PointInTime>().unwrap();
+    assert_eq!(point.resolve(now), point.resolve_with(now, monday));
+    assert_eq!(
+        point.to_absolute_with(now, sunday).to_string(),
+        "17/3/2024 at 0:00"
+    );
+}
+
+#[test]
+fn test_date_time_display_alternate() {
+    for s in [
+        "22/4/1991 at 5:01 PM",
+        "1/1/2000 at 24:00",
+        "29/2/2024 at 0:00",
+    ] {
+        let dt = s.parse::<DateTime>().unwrap();
+        let compact = format!("{dt:#}");
+        assert_eq!(compact, s.replace(" at ", " "));
+        assert_eq!(compact, dt.to_string_no_at());
+        assert_eq!(compact.parse::<DateTime>().unwrap(), dt);
+    }
+}
+
+#[test]
+fn test_offset_time() {
+    let time = "14:00+02:00".parse::<OffsetTime>().unwrap();
+    assert_eq!(
+        time,
+        OffsetTime(Time(Hour::Hour24(14), Minute(0)), Some(UtcOffset(2 * 60)))
+    );
+    assert_eq!(time.to_string(), "14:00 +02:00");
+    let zulu = "14:00Z".parse::<OffsetTime>().unwrap();
+    assert_eq!(zulu.1, Some(UtcOffset(0)));
+    assert_eq!(zulu.to_string(), "14:00 +00:00");
+    let bare = "14:00".parse::<OffsetTime>().unwrap();
+    assert_eq!(bare, OffsetTime(Time(Hour::Hour24(14), Minute(0)), None));
+    assert_eq!(bare.to_string(), "14:00");
+    assert_eq!(
+        "5:30 PM EST".parse::<OffsetTime>().unwrap().1,
+        Some(UtcOffset(-5 * 60))
+    );
+    for time in [time, zulu, bare] {
+        assert_eq!(time.to_string().parse::<OffsetTime>().unwrap(), time);
+    }
+    let date = "15/3/2024".parse::<Date>().unwrap();
+    assert_eq!(
+        time.with_date(date).unwrap().to_utc().to_string(),
+        "15/3/2024 at 12:00 +00:00"
+    );
+    assert_eq!(bare.with_date(date), None);
+    // an attached `Z` is no longer silently dropped
+    assert!("14:00Z".parse::<Time>().is_err());
+    assert_eq!(
+        "15/3/2024 14:00Z"
+            .parse::<OffsetDateTime>()
+            .unwrap()
+            .to_string(),
+        "15/3/2024 at 14:00 +00:00"
+    );
+    assert!("14:00+15:00".parse::<OffsetTime>().is_err());
+}
+
+#[test]
+fn test_point_in_time_humanize() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let humanize = |s: &str| s.parse::<PointInTime>().unwrap().humanize(now);
+    // differences below a minute (the finest resolution available)
+    assert_eq!(humanize("now"), "just now");
+    assert_eq!(humanize("15/3/2024 at 10:30"), "just now");
+    assert_eq!(humanize("in 90 minutes"), "in about 2 hours");
+    assert_eq!(humanize("90 minutes ago"), "about 2 hours ago");
+    assert_eq!(humanize("2 hours ago"), "2 hours ago");
+    assert_eq!(humanize("in 1 minute"), "in 1 minute");
+    assert_eq!(humanize("45 minutes ago"), "45 minutes ago");
+    assert_eq!(humanize("in 3 days and 5 hours"), "in about 3 days");
+    assert_eq!(humanize("tomorrow"), "in about 14 hours");
+    assert_eq!(humanize("2 weeks ago"), "2 weeks ago");
+    assert_eq!(humanize("in 10 days"), "in about 1 week");
+    assert_eq!(humanize("in 364 days"), "in about 1 year");
+    assert_eq!(humanize("in 23 hours and 45 minutes"), "in about 1 day");
+    assert_eq!(humanize("in 2 months"), "in about 2 months");
+    assert_eq!(humanize("1/1/2020"), "about 4 years ago");
+    assert_eq!(humanize("in 1 year"), "in about 1 year");
+}
+
+#[test]
+fn test_named_relative_time_three_word_errors() {
+    assert_eq!(
+        "day after tomorrow".parse::<NamedRelativeTime>().unwrap(),
+        NamedRelativeTime::DayAfterTomorrow
+    );
+    assert_eq!(
+        parse2::<NamedRelativeTime>(quote!(the day-after-tomorrow)).unwrap(),
+        NamedRelativeTime::DayAfterTomorrow
+    );
+    let err = "day after yesterday"
+        .parse::<NamedRelativeTime>()
+        .unwrap_err();
+    assert_eq!(err.message, "expected `tomorrow` after `day after`");
+    assert_eq!(err.offset, "day after ".len());
+    let err = "the day before tomorrow"
+        .parse::<NamedRelativeTime>()
+        .unwrap_err();
+    assert_eq!(err.message, "expected `yesterday` after `day before`");
+    assert_eq!(err.offset, "the day before ".len());
+    let err = "day-after-yesterday"
+        .parse::<NamedRelativeTime>()
+        .unwrap_err();
+    assert_eq!(err.offset, "day-after-".len());
+    let err = "end of the fortnight"
+        .parse::<NamedRelativeTime>()
+        .unwrap_err();
+    assert_eq!(err.offset, "end of the ".len());
+    // a complete phrase leaves any following tokens for the caller
+    let err = "day after tomorrow foo"
+        .parse::<NamedRelativeTime>()
+        .unwrap_err();
+    assert_eq!(err.message, "unexpected token");
+    assert_eq!(err.offset, "day after tomorrow ".len());
+    let err = "3 days after day after yesterday"
+        .parse::<RelativeTime>()
+        .unwrap_err();
+    assert_eq!(err.message, "expected `tomorrow` after `day after`");
+    assert_eq!(err.offset, "3 days after day after ".len());
+    assert_eq!(
+        "the day after tomorrow at 5 PM"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .to_string(),
+        "the day after tomorrow at 5:00 PM"
+    );
+}
+
+#[test]
+fn test_parse_number_words() {
+    assert_eq!(
+        "two days".parse::<Duration>().unwrap(),
+        "2 days".parse::<Duration>().unwrap()
+    );
+    assert_eq!(
+        "Three weeks, nineteen hours and one minute"
+            .parse::<Duration>()
+            .unwrap()
+            .to_string(),
+        "3 weeks, 19 hours, 1 minute"
+    );
+    assert_eq!(
+        "two fortnights".parse::<Duration>().unwrap(),
+        "4 weeks".parse::<Duration>().unwrap()
+    );
+    assert_eq!(
+        "five days ago".parse::<TimeExpression>().unwrap(),
+        "5 days ago".parse::<TimeExpression>().unwrap()
+    );
+    // only number words below twenty are accepted
+    assert!("twenty days".parse::<Duration>().is_err());
+    assert!("zero days".parse::<Duration>().is_err());
+    assert!("two".parse::<Duration>().is_err());
+}
+
+#[test]
+fn test_qualified_time_in_expressions() {
+    let now = "15/3/2024 at 10:30".parse::<DateTime>().unwrap();
+    let expected = RelativeTime::NamedAt(
+        NamedRelativeTime::Tomorrow,
+        QualifiedTime(Time(Hour::Hour12(3, AmPm::PM), Minute(0)), Precision::Exact),
+    );
+    for s in [
+        "tomorrow at 3pm sharp",
+        "tomorrow at exactly 3 PM",
+        "at 3:00 PM sharp tomorrow",
+        "exactly 3 PM tomorrow",
+    ] {
+        assert_eq!(s.parse::<RelativeTime>().unwrap(), expected, "{s}");
+        assert_eq!(
+            s.parse::<TimeExpression>().unwrap(),
+            TimeExpression::Specific(PointInTime::Relative(expected)),
+            "{s}"
+        );
+    }
+    assert_eq!(expected.to_string(), "tomorrow at 3:00 PM sharp");
+    assert_eq!(
+        expected.to_string().parse::<RelativeTime>().unwrap(),
+        expected
+    );
+    // the qualifier does not affect resolution
+    assert_eq!(
+        expected.resolve(now),
+        "tomorrow at 3 PM"
+            .parse::<RelativeTime>()
+            .unwrap()
+            .resolve(now)
+    );
+    assert_eq!(
+        "noon sharp today".parse::<RelativeTime>().unwrap(),
+        RelativeTime::NamedAt(
+            NamedRelativeTime::Today,
+            QualifiedTime(Time(Hour::Hour24(12), Minute(0)), Precision::Exact)
+        )
+    );
+    assert_eq!(
+        "tomorrow at 3pm".parse::<RelativeTime>().unwrap(),
+        RelativeTime::NamedAt(
+            NamedRelativeTime::Tomorrow,
+            Time(Hour::Hour12(3, AmPm::PM), Minute(0)).into()
+        )
+    );
+}
+
+#[test]
+fn test_named_relative_time_case_insensitive_the() {
+    for s in ["end of The week", "End Of THE Week", "end-of-the-week"] {
+        assert_eq!(
+            s.parse::<NamedRelativeTime>().unwrap(),
+            NamedRelativeTime::EndOfWeek,
+            "{s}"
+        );
+    }
+    assert_eq!(
+        "The day after tomorrow"
+            .parse::<NamedRelativeTime>()
+            .unwrap(),
+        NamedRelativeTime::DayAfterTomorrow
+    );
+    assert_eq!(
+        "THE DAY BEFORE YESTERDAY"
+            .parse::<NamedRelativeTime>()
+            .unwrap(),
+        NamedRelativeTime::DayBeforeYesterday
+    );
+}